@@ -32,14 +32,14 @@ impl MavericRoot for Root {
         let text = context.number.to_string();
         commands.add_child(
             0,
-            ButtonNode {
-                style: ButtonStyle,
-                background_color: TEXT_BUTTON_BACKGROUND,
-                border_color: BUTTON_BORDER,
-                visibility: Visibility::Visible,
-                border_radius: BorderRadius::all(Val::Percent(5.0)),
-                marker: Marker,
-                children: (TextNode {
+            ButtonNode::new(
+                ButtonStyle,
+                Visibility::Visible,
+                BUTTON_BORDER,
+                TEXT_BUTTON_BACKGROUND,
+                BorderRadius::all(Val::Percent(5.0)),
+                Marker,
+                (TextNode {
                     text,
                     font_size: BUTTON_FONT_SIZE,
                     color: BUTTON_TEXT_COLOR,
@@ -47,7 +47,7 @@ impl MavericRoot for Root {
                     justify_text: JustifyText::Center,
                     linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
                 },),
-            },
+            ),
             &(),
         )
     }
@@ -80,14 +80,14 @@ impl MavericNode for Buttons {
                 let text: &'static str = button_marker.into();
                 commands.add_child(
                     text,
-                    ButtonNode {
-                        style: ButtonStyle,
-                        background_color: TEXT_BUTTON_BACKGROUND,
-                        border_color: BUTTON_BORDER,
-                        border_radius: BorderRadius::all(Val::Percent(5.0)),
-                        visibility: Visibility::Visible,
-                        marker: button_marker,
-                        children: (TextNode {
+                    ButtonNode::new(
+                        ButtonStyle,
+                        Visibility::Visible,
+                        BUTTON_BORDER,
+                        TEXT_BUTTON_BACKGROUND,
+                        BorderRadius::all(Val::Percent(5.0)),
+                        button_marker,
+                        (TextNode {
                             text,
                             font_size: BUTTON_FONT_SIZE,
                             color: BUTTON_TEXT_COLOR,
@@ -95,7 +95,7 @@ impl MavericNode for Buttons {
                             justify_text: JustifyText::Center,
                             linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
                         },),
-                    },
+                    ),
                     &(),
                 )
             }
@@ -9,10 +9,9 @@ fn main() {
     let mut app = App::new();
 
     app.insert_resource(ClearColor(Color::srgb(0.4, 0.4, 0.4)))
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, MavericWidgetsPlugin))
         .init_resource::<MenuState>()
-        .add_systems(Startup, setup)
-        .add_systems(Update, button_system);
+        .add_systems(Startup, setup);
 
     app.register_transition::<StyleLeftLens>();
     app.register_transition::<TransformScaleLens>();
@@ -26,38 +25,26 @@ fn setup(mut commands: Commands) {
     commands.spawn(Camera2dBundle::default());
 }
 
-fn button_system(
-    mut interaction_query: Query<
-        (&Interaction, &ButtonAction),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut state: ResMut<MenuState>,
-) {
-    for (interaction, action) in &mut interaction_query {
-        if *interaction != Interaction::Pressed {
-            continue;
+fn on_press(state: &mut MenuState, action: &ButtonAction) {
+    match action {
+        ButtonAction::OpenMenu => *state = MenuState::ShowMainMenu,
+        ButtonAction::ChooseLevel => *state = MenuState::ShowLevelsPage(0),
+        ButtonAction::NextLevelsPage => {
+            match *state {
+                MenuState::ShowLevelsPage(x) => *state = MenuState::ShowLevelsPage(x + 1),
+                _ => {}
+            };
         }
-
-        match action {
-            ButtonAction::OpenMenu => *state = MenuState::ShowMainMenu,
-            ButtonAction::ChooseLevel => *state = MenuState::ShowLevelsPage(0),
-            ButtonAction::NextLevelsPage => {
-                match state.as_ref() {
-                    MenuState::ShowLevelsPage(x) => *state = MenuState::ShowLevelsPage(x + 1),
-                    _ => {}
-                };
-            }
-            ButtonAction::PreviousLevelsPage => {
-                match state.as_ref() {
-                    MenuState::ShowLevelsPage(x) => {
-                        *state = MenuState::ShowLevelsPage(x.saturating_sub(1))
-                    }
-                    _ => {}
-                };
-            }
-            ButtonAction::None => {}
-            _ => *state = MenuState::Closed,
+        ButtonAction::PreviousLevelsPage => {
+            match *state {
+                MenuState::ShowLevelsPage(x) => {
+                    *state = MenuState::ShowLevelsPage(x.saturating_sub(1))
+                }
+                _ => {}
+            };
         }
+        ButtonAction::None => {}
+        _ => *state = MenuState::Closed,
     }
 }
 
@@ -107,14 +94,14 @@ impl MavericRoot for MenuRoot {
 }
 
 fn menu_button_node<'w, 's>() -> impl MavericNode<Context<'w, 's> = ()> {
-    ButtonNode {
-        style: OpenMenuButtonStyle,
-        visibility: Visibility::Visible,
-        border_color: BUTTON_BORDER,
-        background_color: ICON_BUTTON_BACKGROUND,
-        border_radius: BorderRadius::all(Val::Percent(5.0)),
-        marker: ButtonAction::OpenMenu,
-        children: (TextNode {
+    ButtonNode::new(
+        OpenMenuButtonStyle,
+        Visibility::Visible,
+        BUTTON_BORDER,
+        ICON_BUTTON_BACKGROUND,
+        BorderRadius::all(Val::Percent(5.0)),
+        (),
+        (TextNode {
             text: ButtonAction::OpenMenu.icon(),
             font: FONT_PATH,
             font_size: ICON_FONT_SIZE,
@@ -122,18 +109,24 @@ fn menu_button_node<'w, 's>() -> impl MavericNode<Context<'w, 's> = ()> {
             justify_text: JustifyText::Center,
             linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
         },),
-    }
+    )
+    .with_handlers(
+        ButtonHandlers::default().on_press(ButtonHandler::update_resource_with(
+            ButtonAction::OpenMenu,
+            on_press,
+        )),
+    )
 }
 
 fn icon_button_node<'w, 's>(button_action: ButtonAction) -> impl MavericNode<Context<'w, 's> = ()> {
-    ButtonNode {
-        style: IconNodeStyle,
-        visibility: Visibility::Visible,
-        border_color: BUTTON_BORDER,
-        background_color: ICON_BUTTON_BACKGROUND,
-        border_radius: BorderRadius::all(Val::Percent(5.0)),
-        marker: button_action,
-        children: (TextNode {
+    ButtonNode::new(
+        IconNodeStyle,
+        Visibility::Visible,
+        BUTTON_BORDER,
+        ICON_BUTTON_BACKGROUND,
+        BorderRadius::all(Val::Percent(5.0)),
+        (),
+        (TextNode {
             text: button_action.icon(),
             font: FONT_PATH,
             font_size: ICON_FONT_SIZE,
@@ -141,18 +134,22 @@ fn icon_button_node<'w, 's>(button_action: ButtonAction) -> impl MavericNode<Con
             justify_text: JustifyText::Center,
             linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
         },),
-    }
+    )
+    .with_handlers(
+        ButtonHandlers::default()
+            .on_press(ButtonHandler::update_resource_with(button_action, on_press)),
+    )
 }
 
 fn text_button_node<'w, 's>(button_action: ButtonAction) -> impl MavericNode<Context<'w, 's> = ()> {
-    ButtonNode {
-        style: TextButtonStyle,
-        visibility: Visibility::Visible,
-        border_color: BUTTON_BORDER,
-        background_color: TEXT_BUTTON_BACKGROUND,
-        border_radius: BorderRadius::all(Val::Percent(5.0)),
-        marker: button_action,
-        children: (TextNode {
+    ButtonNode::new(
+        TextButtonStyle,
+        Visibility::Visible,
+        BUTTON_BORDER,
+        TEXT_BUTTON_BACKGROUND,
+        BorderRadius::all(Val::Percent(5.0)),
+        (),
+        (TextNode {
             text: button_action.text(),
             font: FONT_PATH,
             font_size: BUTTON_FONT_SIZE,
@@ -160,21 +157,25 @@ fn text_button_node<'w, 's>(button_action: ButtonAction) -> impl MavericNode<Con
             justify_text: JustifyText::Center,
             linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
         },),
-    }
+    )
+    .with_handlers(
+        ButtonHandlers::default()
+            .on_press(ButtonHandler::update_resource_with(button_action, on_press)),
+    )
 }
 
 fn text_and_image_button_node<'w, 's>(
     button_action: ButtonAction,
     image_path: &'static str,
 ) -> impl MavericNode<Context<'w, 's> = ()> {
-    ButtonNode {
-        style: TextButtonStyle,
-        visibility: Visibility::Visible,
-        border_color: BUTTON_BORDER,
-        background_color: TEXT_BUTTON_BACKGROUND,
-        border_radius: BorderRadius::all(Val::Percent(5.0)),
-        marker: button_action,
-        children: (
+    ButtonNode::new(
+        TextButtonStyle,
+        Visibility::Visible,
+        BUTTON_BORDER,
+        TEXT_BUTTON_BACKGROUND,
+        BorderRadius::all(Val::Percent(5.0)),
+        (),
+        (
             TextNode {
                 text: button_action.text(),
                 font: FONT_PATH,
@@ -189,7 +190,11 @@ fn text_and_image_button_node<'w, 's>(
                 style: SmallImageNodeStyle,
             },
         ),
-    }
+    )
+    .with_handlers(
+        ButtonHandlers::default()
+            .on_press(ButtonHandler::update_resource_with(button_action, on_press)),
+    )
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -306,7 +311,8 @@ impl MavericNode for LevelMenuArrows {
     }
 
     fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
-        let Some((args, context, mut commands)) = commands.unordered_children_with_node_and_context()
+        let Some((args, context, mut commands)) =
+            commands.unordered_children_with_node_and_context()
         else {
             return;
         };
@@ -477,7 +483,7 @@ impl IntoBundle for TextButtonStyle {
     }
 }
 
-#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Component)]
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
 pub enum ButtonAction {
     OpenMenu,
     Resume,
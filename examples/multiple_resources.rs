@@ -57,14 +57,14 @@ impl MavericRoot for Root {
         let (color, color_name) = context.color_state.get_color_and_name();
         commands.add_child(
             0,
-            ButtonNode {
-                style: ButtonStyle { top: Val::Px(0.0) },
-                background_color: color,
-                border_color: BUTTON_BORDER,
-                visibility: Visibility::Visible,
-                marker: ButtonMarker::Number,
-                border_radius: BorderRadius::all(Val::Percent(5.0)),
-                children: (TextNode {
+            ButtonNode::new(
+                ButtonStyle { top: Val::Px(0.0) },
+                Visibility::Visible,
+                BUTTON_BORDER,
+                color,
+                BorderRadius::all(Val::Percent(5.0)),
+                ButtonMarker::Number,
+                (TextNode {
                     text,
                     font_size: BUTTON_FONT_SIZE,
                     color: BUTTON_TEXT_COLOR,
@@ -72,22 +72,22 @@ impl MavericRoot for Root {
                     justify_text: JustifyText::Center,
                     linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
                 },),
-            },
+            ),
             &(),
         );
 
         commands.add_child(
             1,
-            ButtonNode {
-                style: ButtonStyle {
+            ButtonNode::new(
+                ButtonStyle {
                     top: Val::Px(100.0),
                 },
-                background_color: color,
-                border_color: BUTTON_BORDER,
-                visibility: Visibility::Visible,
-                marker: ButtonMarker::Color,
-                border_radius: BorderRadius::all(Val::Percent(5.0)),
-                children: (TextNode {
+                Visibility::Visible,
+                BUTTON_BORDER,
+                color,
+                BorderRadius::all(Val::Percent(5.0)),
+                ButtonMarker::Color,
+                (TextNode {
                     text: color_name,
                     font_size: BUTTON_FONT_SIZE,
                     color: BUTTON_TEXT_COLOR,
@@ -95,7 +95,7 @@ impl MavericRoot for Root {
                     justify_text: JustifyText::Center,
                     linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
                 },),
-            },
+            ),
             &(),
         );
     }
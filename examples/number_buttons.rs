@@ -15,11 +15,10 @@ fn main() {
     let mut app = App::new();
 
     app.insert_resource(ClearColor(Color::srgb(0.4, 0.4, 0.4)))
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, MavericWidgetsPlugin))
         .register_maveric::<Root>()
         .init_resource::<UIState>()
-        .add_systems(Startup, setup)
-        .add_systems(Update, button_system);
+        .add_systems(Startup, setup);
 
     app.register_transition::<(TransformRotationZLens, TransformScaleLens)>();
 
@@ -67,15 +66,12 @@ pub fn get_button_left_top(state: &UIState, number: &u32) -> (Val, Val) {
     (left, top)
 }
 
-#[derive(Debug, Eq, PartialEq, Component, Hash, Clone, Copy, IntoStaticStr, Display)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, IntoStaticStr, Display)]
 pub enum Command {
     AddNew,
     Reset,
 }
 
-#[derive(Debug, Eq, PartialEq, Component, Hash, Clone, Copy)]
-pub struct DynamicButtonComponent(u32);
-
 #[derive(Eq, PartialEq, Debug, Default)]
 pub struct Root;
 
@@ -117,14 +113,14 @@ impl MavericNode for CommandGrid {
         };
         for command in [Command::AddNew, Command::Reset] {
             let key: &'static str = command.into();
-            let node = ButtonNode {
-                style: ButtonStyle,
-                visibility: Visibility::Visible,
-                border_color: BUTTON_BORDER,
-                background_color: TEXT_BUTTON_BACKGROUND,
-                border_radius: BorderRadius::all(Val::Percent(5.0)),
-                marker: command,
-                children: (TextNode {
+            let node = ButtonNode::new(
+                ButtonStyle,
+                Visibility::Visible,
+                BUTTON_BORDER,
+                NORMAL_BUTTON,
+                BorderRadius::all(Val::Percent(5.0)),
+                (),
+                (TextNode {
                     text: command.to_string(),
                     font: FONT_PATH,
                     font_size: BUTTON_FONT_SIZE,
@@ -132,7 +128,17 @@ impl MavericNode for CommandGrid {
                     justify_text: JustifyText::Center,
                     linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
                 },),
-            };
+            )
+            .with_handlers(
+                ButtonHandlers::default().on_press(ButtonHandler::update_resource_with(
+                    command,
+                    |state: &mut UIState, command| match command {
+                        Command::AddNew => state.add(),
+                        Command::Reset => state.reset(),
+                    },
+                )),
+            )
+            .with_styles(button_styles());
 
             commands.add_child(key, node, &context);
         }
@@ -169,14 +175,14 @@ impl MavericNode for DynamicGrid {
 
         {
             for number in context.dynamic_buttons.iter().cloned() {
-                let node = ButtonNode {
-                    style: ButtonStyle,
-                    visibility: Visibility::Visible,
-                    border_color: BUTTON_BORDER,
-                    background_color: TEXT_BUTTON_BACKGROUND,
-                    border_radius: BorderRadius::all(Val::Percent(5.0)),
-                    marker: DynamicButtonComponent(number),
-                    children: (TextNode {
+                let node = ButtonNode::new(
+                    ButtonStyle,
+                    Visibility::Visible,
+                    BUTTON_BORDER,
+                    NORMAL_BUTTON,
+                    BorderRadius::all(Val::Percent(5.0)),
+                    (),
+                    (TextNode {
                         text: number.to_string(),
                         font: FONT_PATH,
                         font_size: BUTTON_FONT_SIZE,
@@ -184,7 +190,13 @@ impl MavericNode for DynamicGrid {
                         justify_text: JustifyText::Center,
                         linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
                     },),
-                };
+                )
+                .with_handlers(ButtonHandlers::default().on_press(
+                    ButtonHandler::update_resource_with(number, |state: &mut UIState, n| {
+                        state.remove_or_readd(*n)
+                    }),
+                ))
+                .with_styles(button_styles());
 
                 let node = node
                     .with_transition_in_out::<(TransformRotationZLens, TransformScaleLens)>(
@@ -207,47 +219,19 @@ const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
 const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
 const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
 
-fn button_system(
-    mut interaction_query: Query<
-        (
-            &Interaction,
-            &mut BackgroundColor,
-            &mut BorderColor,
-            Option<&Command>,
-            Option<&DynamicButtonComponent>,
-        ),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut state: ResMut<UIState>,
-) {
-    for (interaction, mut color, mut border_color, command, dynamic_button) in
-        &mut interaction_query
-    {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = PRESSED_BUTTON.into();
-                border_color.0 = Color::Srgba(Srgba::RED);
-
-                if let Some(command) = command {
-                    match command {
-                        Command::AddNew => state.add(),
-                        Command::Reset => state.reset(),
-                    }
-                };
-
-                if let Some(DynamicButtonComponent(index)) = dynamic_button {
-                    state.remove_or_readd(*index)
-                }
-            }
-            Interaction::Hovered => {
-                *color = HOVERED_BUTTON.into();
-                border_color.0 = Color::WHITE;
-            }
-            Interaction::None => {
-                *color = NORMAL_BUTTON.into();
-                border_color.0 = Color::BLACK;
-            }
-        }
+fn button_styles() -> ButtonStyles {
+    ButtonStyles {
+        hovered: Some(ButtonVariant {
+            background_color: HOVERED_BUTTON,
+            border_color: Color::WHITE,
+            scale: 1.0,
+        }),
+        pressed: Some(ButtonVariant {
+            background_color: PRESSED_BUTTON,
+            border_color: Color::Srgba(Srgba::RED),
+            scale: 1.0,
+        }),
+        ..default()
     }
 }
 
@@ -1,10 +1,165 @@
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Index};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, spanned::Spanned, token::Comma,
+    ConstParam, DeriveInput, GenericParam, Index, TypeParam,
+};
 
-/// Implement `SystemParam` to use a struct as a parameter in a system
-#[proc_macro_derive(HasChanged)]
-pub fn derive_system_param(input: TokenStream) -> TokenStream {
+/// Implement `HasChanged` for a struct.
+/// A struct has changed if any of its fields have changed.
+/// Enums are not supported because a change of variant cannot be detected.
+///
+/// Fields can be marked with `#[has_changed(skip)]` to ignore them
+/// or `#[has_changed(with = path)]` to use a function `fn(&Field) -> bool` instead of `HasChanged`
+#[proc_macro_derive(HasChanged, attributes(has_changed))]
+pub fn derive_has_changed(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let mut tracked_types = Vec::new();
+    let has_changed_body;
+    let changed_paths_body;
+
+    match &ast.data {
+        syn::Data::Struct(data) => {
+            let mut has_changed_exprs = Vec::new();
+            let mut changed_paths_stmts = Vec::new();
+            for (i, field) in data.fields.iter().enumerate() {
+                let tracking = match change_tracking(field) {
+                    Ok(tracking) => tracking,
+                    Err(err) => return err.into_compile_error().into(),
+                };
+                let member = field_member(field, i);
+                let access = quote!(&self.#member);
+                let label = quote!(stringify!(#member));
+                if let Some((has_changed, changed_paths)) =
+                    field_change_tokens(&access, &label, &tracking)
+                {
+                    has_changed_exprs.push(has_changed);
+                    changed_paths_stmts.push(changed_paths);
+                }
+                if matches!(tracking, ChangeTracking::Tracked) {
+                    tracked_types.push(&field.ty);
+                }
+            }
+            has_changed_body = quote!(false #(|| #has_changed_exprs)*);
+            changed_paths_body = quote!(#(#changed_paths_stmts)*);
+        }
+        syn::Data::Enum(_) => {
+            // `has_changed` only sees the current value, so it cannot tell whether the variant has changed
+            return syn::Error::new(
+                ast.span(),
+                "`HasChanged` cannot be derived for an `enum` because a change of variant cannot be detected from the current value alone. \
+                Store it in a resource or component and use `Res` or `Ref`, or implement `HasChanged` manually",
+            )
+            .into_compile_error()
+            .into();
+        }
+        syn::Data::Union(_) => {
+            return syn::Error::new(ast.span(), "Invalid `HasChanged` type: expected a `struct`")
+                .into_compile_error()
+                .into();
+        }
+    }
+
+    let (impl_generics, ty_generics, _where_clause) = ast.generics.split_for_impl();
+
+    let mut has_changed_generics = ast.generics.clone();
+    let has_changed_where_clause = has_changed_generics.make_where_clause();
+    for field_type in tracked_types {
+        has_changed_where_clause
+            .predicates
+            .push(parse_quote!(#field_type: maveric::has_changed::HasChanged));
+    }
+
+    let name = &ast.ident;
+
+    TokenStream::from(quote! {
+        impl #impl_generics maveric::has_changed::HasChanged for #name #ty_generics #has_changed_where_clause
+        {
+            fn has_changed(&self) -> bool {
+                #has_changed_body
+            }
+
+            #[allow(unused_variables)]
+            fn changed_paths(&self, path: &str, changes: &mut Vec<String>) {
+                #changed_paths_body
+            }
+        }
+    })
+}
+
+/// How changes to a field are detected
+enum ChangeTracking {
+    /// Use the field's `HasChanged` implementation
+    Tracked,
+    /// Ignore changes to the field
+    Skip,
+    /// Use a function `fn(&Field) -> bool`
+    With(syn::Path),
+}
+
+/// Read the `#[has_changed(...)]` attributes of a field
+fn change_tracking(field: &syn::Field) -> syn::Result<ChangeTracking> {
+    let mut tracking = ChangeTracking::Tracked;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("has_changed"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                tracking = ChangeTracking::Skip;
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                tracking = ChangeTracking::With(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta
+                    .error("unsupported has_changed attribute: expected `skip` or `with = path`"))
+            }
+        })?;
+    }
+    Ok(tracking)
+}
+
+/// The name or index of a field
+fn field_member(field: &syn::Field, index: usize) -> proc_macro2::TokenStream {
+    let index = Index::from(index);
+    field
+        .ident
+        .as_ref()
+        .map(|f| quote! { #f })
+        .unwrap_or_else(|| quote! { #index })
+}
+
+/// The `has_changed` expression and `changed_paths` statement for a field, or `None` if the field is skipped
+fn field_change_tokens(
+    access: &proc_macro2::TokenStream,
+    label: &proc_macro2::TokenStream,
+    tracking: &ChangeTracking,
+) -> Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    match tracking {
+        ChangeTracking::Skip => None,
+        ChangeTracking::Tracked => Some((
+            quote!(maveric::has_changed::HasChanged::has_changed(#access)),
+            quote!(maveric::has_changed::HasChanged::changed_paths(#access, &format!("{}.{}", path, #label), changes);),
+        )),
+        ChangeTracking::With(with) => Some((
+            quote!(#with(#access)),
+            quote! {
+                if #with(#access) {
+                    changes.push(format!("{}.{}", path, #label));
+                }
+            },
+        )),
+    }
+}
+
+/// Implement `SystemParam`, `ReadOnlySystemParam` and `HasChanged` so that a struct can be used as a `MavericContext`.
+/// Every field must be a read-only `SystemParam`.
+/// Fields marked with `#[maveric(ignore_changes)]` can be read but changes to them will not cause an update.
+#[proc_macro_derive(MavericContext, attributes(maveric))]
+pub fn derive_maveric_context(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let syn::Data::Struct(syn::DataStruct {
         fields: field_definitions,
@@ -13,23 +168,30 @@ pub fn derive_system_param(input: TokenStream) -> TokenStream {
     else {
         return syn::Error::new(
             ast.span(),
-            "Invalid `SystemParam` type: expected a `struct`",
+            "Invalid `MavericContext` type: expected a `struct`",
         )
         .into_compile_error()
         .into();
     };
 
+    let mut field_locals = Vec::new();
     let mut fields = Vec::new();
     let mut field_types = Vec::new();
+    let mut tracked_fields = Vec::new();
+    let mut tracked_field_types = Vec::new();
     for (i, field) in field_definitions.iter().enumerate() {
-        let i = Index::from(i);
-        fields.push(
-            field
-                .ident
-                .as_ref()
-                .map(|f| quote! { #f })
-                .unwrap_or_else(|| quote! { #i }),
-        );
+        let ignore_changes = match is_ignore_changes(field) {
+            Ok(ignore_changes) => ignore_changes,
+            Err(err) => return err.into_compile_error().into(),
+        };
+
+        field_locals.push(format_ident!("f{i}"));
+        let member = field_member(field, i);
+        if !ignore_changes {
+            tracked_fields.push(member.clone());
+            tracked_field_types.push(&field.ty);
+        }
+        fields.push(member);
         field_types.push(&field.ty);
     }
 
@@ -52,30 +214,178 @@ pub fn derive_system_param(input: TokenStream) -> TokenStream {
         }
     }
 
-    let (impl_generics, ty_generics, _where_clause) = generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let lifetimeless_generics: Vec<_> = generics
+        .params
+        .iter()
+        .filter(|g| !matches!(g, GenericParam::Lifetime(_)))
+        .collect();
+
+    let shadowed_lifetimes: Vec<_> = generics.lifetimes().map(|_| quote!('_)).collect();
+
+    let mut punctuated_generics = Punctuated::<_, Comma>::new();
+    punctuated_generics.extend(lifetimeless_generics.iter().map(|g| match g {
+        GenericParam::Type(g) => GenericParam::Type(TypeParam {
+            default: None,
+            ..g.clone()
+        }),
+        GenericParam::Const(g) => GenericParam::Const(ConstParam {
+            default: None,
+            ..g.clone()
+        }),
+        _ => unreachable!(),
+    }));
+
+    let mut punctuated_generic_idents = Punctuated::<_, Comma>::new();
+    punctuated_generic_idents.extend(lifetimeless_generics.iter().map(|g| match g {
+        GenericParam::Type(g) => &g.ident,
+        GenericParam::Const(g) => &g.ident,
+        _ => unreachable!(),
+    }));
+
+    let punctuated_generics_no_bounds: Punctuated<_, Comma> = lifetimeless_generics
+        .iter()
+        .map(|&g| match g.clone() {
+            GenericParam::Type(mut g) => {
+                g.bounds.clear();
+                GenericParam::Type(g)
+            }
+            g => g,
+        })
+        .collect();
+
+    let mut tuple_types: Vec<_> = field_types.iter().map(|x| quote! { #x }).collect();
+    let mut tuple_patterns: Vec<_> = field_locals.iter().map(|x| quote! { #x }).collect();
+
+    // If the number of fields exceeds the 16-parameter limit,
+    // fold the fields into tuples of tuples until we are below the limit.
+    const LIMIT: usize = 16;
+    while tuple_types.len() > LIMIT {
+        let end = Vec::from_iter(tuple_types.drain(..LIMIT));
+        tuple_types.push(parse_quote!( (#(#end,)*) ));
+
+        let end = Vec::from_iter(tuple_patterns.drain(..LIMIT));
+        tuple_patterns.push(parse_quote!( (#(#end,)*) ));
+    }
+
+    // Every field must be read-only. The bound is spanned to the field so that the error points at it.
+    let mut read_only_generics = generics.clone();
+    let read_only_where_clause = read_only_generics.make_where_clause();
+    for field_type in &field_types {
+        read_only_where_clause.predicates.push(parse_quote!(
+            #field_type: maveric::maveric_context::ReadOnlyContextParam
+        ));
+    }
+    let read_only_assertions = field_types.iter().map(|field_type| {
+        quote_spanned! {field_type.span()=>
+            assert_read_only::<#field_type>();
+        }
+    });
 
     let mut has_changed_generics = generics.clone();
     let has_changed_where_clause = has_changed_generics.make_where_clause();
-    for field_type in &field_types {
+    for field_type in &tracked_field_types {
         has_changed_where_clause
             .predicates
-            .push(syn::parse_quote!(#field_type: maveric::has_changed::HasChanged));
+            .push(parse_quote!(#field_type: maveric::has_changed::HasChanged));
     }
 
-    let struct_name = &ast.ident;
-
-    let has_changed_impl = fields
+    let (has_changed_impl, changed_paths_impl): (Vec<_>, Vec<_>) = tracked_fields
         .iter()
-        .map(|field| quote!(maveric::has_changed::HasChanged::has_changed(&self.#field) ));
+        .filter_map(|field| {
+            field_change_tokens(
+                &quote!(&self.#field),
+                &quote!(stringify!(#field)),
+                &ChangeTracking::Tracked,
+            )
+        })
+        .unzip();
+
+    let struct_name = &ast.ident;
+    let state_struct_visibility = &ast.vis;
+    let fields_alias = format_ident!("__{}Fields", struct_name);
+    let state_struct_name = format_ident!("__{}State", struct_name);
+    let path = quote!(bevy::ecs);
 
     TokenStream::from(quote! {
+        const _: () = {
+            // Allows rebinding the lifetimes of each field type.
+            type #fields_alias <'w, 's, #punctuated_generics_no_bounds> = (#(#tuple_types,)*);
 
+            #[doc(hidden)]
+            #state_struct_visibility struct #state_struct_name <#(#lifetimeless_generics,)*>
+            #where_clause {
+                state: <#fields_alias::<'static, 'static, #punctuated_generic_idents> as #path::system::SystemParam>::State,
+            }
 
-        impl #impl_generics maveric::has_changed::HasChanged for #struct_name #ty_generics #has_changed_where_clause
-        {
-            fn has_changed(&self) -> bool {
-                #(#has_changed_impl)||*
+            unsafe impl<#punctuated_generics> #path::system::SystemParam for
+                #struct_name <#(#shadowed_lifetimes,)* #punctuated_generic_idents> #where_clause
+            {
+                type State = #state_struct_name<#punctuated_generic_idents>;
+                type Item<'w, 's> = #struct_name #ty_generics;
+
+                fn init_state(world: &mut #path::world::World, system_meta: &mut #path::system::SystemMeta) -> Self::State {
+                    #state_struct_name {
+                        state: <#fields_alias::<'_, '_, #punctuated_generic_idents> as #path::system::SystemParam>::init_state(world, system_meta),
+                    }
+                }
+
+                unsafe fn new_archetype(state: &mut Self::State, archetype: &#path::archetype::Archetype, system_meta: &mut #path::system::SystemMeta) {
+                    <#fields_alias::<'_, '_, #punctuated_generic_idents> as #path::system::SystemParam>::new_archetype(&mut state.state, archetype, system_meta)
+                }
+
+                unsafe fn get_param<'w, 's>(
+                    state: &'s mut Self::State,
+                    system_meta: &#path::system::SystemMeta,
+                    world: #path::world::unsafe_world_cell::UnsafeWorldCell<'w>,
+                    change_tick: #path::component::Tick,
+                ) -> Self::Item<'w, 's> {
+                    let (#(#tuple_patterns,)*) = <
+                        (#(#tuple_types,)*) as #path::system::SystemParam
+                    >::get_param(&mut state.state, system_meta, world, change_tick);
+                    #struct_name {
+                        #(#fields: #field_locals,)*
+                    }
+                }
             }
-        }
+
+            // Safety: Each field is `ReadOnlySystemParam`, so this can only read from the `World`
+            unsafe impl<'w, 's, #punctuated_generics> #path::system::ReadOnlySystemParam for #struct_name #ty_generics #read_only_where_clause {}
+
+            #[allow(dead_code)]
+            fn assert_fields_read_only #impl_generics () #where_clause {
+                fn assert_read_only<T: maveric::maveric_context::ReadOnlyContextParam>() {}
+                #(#read_only_assertions)*
+            }
+
+            impl #impl_generics maveric::has_changed::HasChanged for #struct_name #ty_generics #has_changed_where_clause
+            {
+                fn has_changed(&self) -> bool {
+                    false #(|| #has_changed_impl)*
+                }
+
+                #[allow(unused_variables)]
+                fn changed_paths(&self, path: &str, changes: &mut Vec<String>) {
+                    #(#changed_paths_impl)*
+                }
+            }
+        };
     })
 }
+
+/// Whether a field is marked with `#[maveric(ignore_changes)]`
+fn is_ignore_changes(field: &syn::Field) -> syn::Result<bool> {
+    let mut ignore_changes = false;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("maveric")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("ignore_changes") {
+                ignore_changes = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported maveric attribute: expected `ignore_changes`"))
+            }
+        })?;
+    }
+    Ok(ignore_changes)
+}
@@ -0,0 +1,295 @@
+use crate::prelude::*;
+use std::any::Any;
+
+/// Values provided by `Provider` ancestors of a node.
+/// Read them with `ambient` on `SetComponentCommands` or `SetChildrenCommands`
+#[derive(Clone, Copy, Default)]
+pub struct Ambient<'a> {
+    top: Option<&'a AmbientEntry<'a>>,
+}
+
+struct AmbientEntry<'a> {
+    value: &'a (dyn Any + Send + Sync),
+    changed: bool,
+    parent: Ambient<'a>,
+}
+
+impl<'a> Ambient<'a> {
+    fn entries(self) -> impl Iterator<Item = &'a AmbientEntry<'a>> {
+        std::iter::successors(self.top, |entry| entry.parent.top)
+    }
+
+    fn entry<T: Send + Sync + 'static>(self) -> Option<&'a AmbientEntry<'a>> {
+        self.entries().find(|entry| entry.value.is::<T>())
+    }
+
+    /// The value provided by the nearest `Provider<T, _>` ancestor
+    #[must_use]
+    pub fn get<T: Send + Sync + 'static>(self) -> Option<&'a T> {
+        self.entry::<T>()?.value.downcast_ref()
+    }
+
+    /// Whether the value provided by the nearest `Provider<T, _>` ancestor has changed
+    #[must_use]
+    pub fn has_changed<T: Send + Sync + 'static>(self) -> bool {
+        self.entry::<T>().is_some_and(|entry| entry.changed)
+    }
+
+    /// Whether any provided value has changed
+    pub(crate) fn any_changed(self) -> bool {
+        self.entries().any(|entry| entry.changed)
+    }
+
+    /// Call `f` with this and one more provided value
+    pub(crate) fn with<Out>(
+        self,
+        value: &(dyn Any + Send + Sync),
+        changed: bool,
+        f: impl FnOnce(Ambient<'_>) -> Out,
+    ) -> Out {
+        let entry = AmbientEntry {
+            value,
+            changed,
+            parent: self,
+        };
+        f(Ambient { top: Some(&entry) })
+    }
+
+    /// Call `f` with this and the given values, which are ordered from the innermost outwards
+    fn with_all<Out>(
+        self,
+        values: &[&(dyn Any + Send + Sync)],
+        f: impl FnOnce(Ambient<'_>) -> Out,
+    ) -> Out {
+        match values.split_last() {
+            Some((outermost, rest)) => {
+                self.with(*outermost, false, |ambient| ambient.with_all(rest, f))
+            }
+            None => f(self),
+        }
+    }
+
+    /// Call `f` with the unchanged values provided by the `Provider` ancestors of `entity`
+    pub(crate) fn with_ancestors<Out>(
+        world: &World,
+        entity: Entity,
+        f: impl FnOnce(Ambient<'_>) -> Out,
+    ) -> Out {
+        let values: Vec<_> = std::iter::successors(world.get::<Parent>(entity), |parent| {
+            world.get::<Parent>(parent.get())
+        })
+        .filter_map(|parent| {
+            let provider = world.get::<AmbientProvider>(parent.get())?;
+            (provider.get_value)(world, parent.get())
+        })
+        .collect();
+
+        Ambient::default().with_all(&values, f)
+    }
+}
+
+/// Allows values to be provided to descendants which are updated without updating the `Provider`
+#[derive(Component)]
+struct AmbientProvider {
+    get_value: for<'w> fn(&'w World, Entity) -> Option<&'w (dyn Any + Send + Sync)>,
+}
+
+fn provided_value<T: PartialEq + Send + Sync + 'static, N: MavericNode + Clone>(
+    world: &World,
+    entity: Entity,
+) -> Option<&(dyn Any + Send + Sync)> {
+    let component = world.get::<MavericNodeComponent<Provider<T, N>>>(entity)?;
+    Some(&component.node.value)
+}
+
+impl std::fmt::Debug for Ambient<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Ambient")
+            .field("count", &self.entries().count())
+            .finish()
+    }
+}
+
+/// Provides a value to every descendant of `node`.
+///
+/// Descendants read it with `ambient` on their commands.
+/// When the value changes, only descendants which read it are hot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provider<T: PartialEq + Send + Sync + 'static, N: MavericNode + Clone> {
+    pub value: T,
+    pub node: N,
+}
+
+impl<T: PartialEq + Send + Sync + 'static, N: MavericNode + Clone> Provider<T, N> {
+    pub const fn new(value: T, node: N) -> Self {
+        Self { value, node }
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static, N: MavericNode + Clone> MavericNode for Provider<T, N> {
+    type Context<'w, 's> = N::Context<'w, 's>;
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.insert_static_bundle(AmbientProvider {
+            get_value: provided_value::<T, N>,
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let (args, world, ec, alloc) = commands.into_parts();
+        if !args.should_visit_children() {
+            return;
+        }
+
+        let changed = match args.event {
+            SetEvent::Created | SetEvent::Undeleted => true,
+            SetEvent::Updated => {
+                !matches!(args.previous, Some(previous) if previous.value == args.node.value)
+            }
+        };
+
+        args.ambient.with(&args.node.value, changed, |ambient| {
+            let mut child_commands = UnorderedChildCommands::<R>::new(ec, world, alloc, ambient);
+            child_commands.add_child(0, args.node.node.clone(), args.context);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default, Resource)]
+    struct Settings {
+        theme: &'static str,
+        title: &'static str,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Theme(&'static str);
+
+    #[derive(Debug, Clone, PartialEq, Component)]
+    struct ThemeName(&'static str);
+
+    static LABELS_UPDATED: AtomicUsize = AtomicUsize::new(0);
+    static THEMED_UPDATED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Settings>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(
+                0,
+                Provider::new(Theme(context.theme), Panel(context.title)),
+                &(),
+            );
+        }
+    }
+
+    /// A node which does not read the theme but has a child which does
+    #[derive(Debug, Clone, PartialEq)]
+    struct Panel(&'static str);
+
+    impl MavericNode for Panel {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+            let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+                return;
+            };
+            commands.add_child(0, Label(node.0), &());
+            commands.add_child(1, Themed, &());
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label(&'static str);
+
+    impl MavericNode for Label {
+        type Context<'w, 's> = ();
+
+        fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+            commands
+                .advanced(|args, _| {
+                    if args.is_hot() {
+                        LABELS_UPDATED.fetch_add(1, Ordering::SeqCst);
+                    }
+                })
+                .finish();
+        }
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Themed;
+
+    impl MavericNode for Themed {
+        type Context<'w, 's> = ();
+
+        fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+            let theme = commands.ambient::<Theme>().map_or("none", |x| x.0);
+            commands
+                .advanced(|args, commands| {
+                    if args.is_hot() {
+                        THEMED_UPDATED.fetch_add(1, Ordering::SeqCst);
+                        commands.insert(ThemeName(theme));
+                    }
+                })
+                .finish();
+        }
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn theme_name(app: &mut App) -> &'static str {
+        app.world_mut().query::<&ThemeName>().single(app.world()).0
+    }
+
+    #[test]
+    pub fn test_provider() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.insert_resource(Settings {
+            theme: "light",
+            title: "hello",
+        });
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(theme_name(&mut app), "light");
+
+        app.world_mut().resource_mut::<Settings>().theme = "dark";
+        app.update();
+        assert_eq!(theme_name(&mut app), "dark");
+        assert_eq!(
+            LABELS_UPDATED.load(Ordering::SeqCst),
+            1,
+            "Nodes which do not read the theme should not be updated"
+        );
+        assert_eq!(THEMED_UPDATED.load(Ordering::SeqCst), 2);
+
+        app.world_mut().resource_mut::<Settings>().title = "goodbye";
+        app.update();
+        assert_eq!(LABELS_UPDATED.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            THEMED_UPDATED.load(Ordering::SeqCst),
+            2,
+            "Nodes which read the theme should not be updated when it has not changed"
+        );
+    }
+}
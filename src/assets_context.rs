@@ -0,0 +1,194 @@
+use crate::{has_changed::HasChanged, prelude::*};
+use bevy::ecs::{
+    component::Tick,
+    event::ManualEventReader,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use std::ops::Deref;
+
+/// A collection of assets.
+/// This is only marked as changed when an asset is added, modified, loaded or removed.
+///
+/// `Res<Assets<A>>` can't be used as a context directly because it is changed every frame,
+/// and telling real changes apart means reading the `AssetEvent`s, which needs state of its own
+pub struct AssetsContext<'w, 's, A: Asset> {
+    assets: Res<'w, Assets<A>>,
+    changed: &'s [AssetId<A>],
+}
+
+impl<A: Asset> AssetsContext<'_, '_, A> {
+    /// The ids of assets which were added, modified, loaded or removed since the system last ran
+    #[must_use]
+    pub const fn changed_ids(&self) -> &[AssetId<A>] {
+        self.changed
+    }
+
+    /// Whether this asset was added, modified, loaded or removed since the system last ran
+    #[must_use]
+    pub fn is_asset_changed(&self, id: impl Into<AssetId<A>>) -> bool {
+        let id = id.into();
+        self.changed.contains(&id)
+    }
+}
+
+impl<A: Asset> HasChanged for AssetsContext<'_, '_, A> {
+    fn has_changed(&self) -> bool {
+        !self.changed.is_empty()
+    }
+}
+
+impl<A: Asset> Deref for AssetsContext<'_, '_, A> {
+    type Target = Assets<A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.assets
+    }
+}
+
+impl<A: Asset> std::fmt::Debug for AssetsContext<'_, '_, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AssetsContext")
+            .field("len", &self.assets.len())
+            .field("changed", &self.changed)
+            .finish()
+    }
+}
+
+unsafe impl<A: Asset> ReadOnlySystemParam for AssetsContext<'_, '_, A> {}
+
+pub struct AssetsContextState<A: Asset> {
+    assets_state: <Res<'static, Assets<A>> as SystemParam>::State,
+    events_state: <Res<'static, Events<AssetEvent<A>>> as SystemParam>::State,
+    reader: ManualEventReader<AssetEvent<A>>,
+    changed: Vec<AssetId<A>>,
+}
+
+unsafe impl<A: Asset> SystemParam for AssetsContext<'_, '_, A> {
+    type State = AssetsContextState<A>;
+    type Item<'world, 'state> = AssetsContext<'world, 'state, A>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        AssetsContextState {
+            assets_state: <Res<'static, Assets<A>> as SystemParam>::init_state(world, system_meta),
+            events_state: <Res<'static, Events<AssetEvent<A>>> as SystemParam>::init_state(
+                world,
+                system_meta,
+            ),
+            reader: ManualEventReader::default(),
+            changed: vec![],
+        }
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        let assets = <Res<'static, Assets<A>> as SystemParam>::get_param(
+            &mut state.assets_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+        let events = <Res<'static, Events<AssetEvent<A>>> as SystemParam>::get_param(
+            &mut state.events_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+
+        state.changed.clear();
+        for event in state.reader.read(&events) {
+            match event {
+                AssetEvent::Added { id }
+                | AssetEvent::Modified { id }
+                | AssetEvent::Removed { id }
+                | AssetEvent::LoadedWithDependencies { id } => {
+                    if !state.changed.contains(id) {
+                        state.changed.push(*id);
+                    }
+                }
+                AssetEvent::Unused { .. } => {}
+            }
+        }
+
+        AssetsContext {
+            assets,
+            changed: &state.changed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{asset::AssetPlugin, reflect::TypePath};
+
+    #[test]
+    pub fn test_assets_context() {
+        #[derive(Debug, Asset, TypePath)]
+        struct Label(&'static str);
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = AssetsContext<'w, 's, Label>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                for (index, (_, label)) in context.iter().enumerate() {
+                    #[allow(clippy::cast_possible_truncation)]
+                    commands.add_child(index as u32, LabelNode(label.0), &());
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct LabelNode(&'static str);
+
+        impl MavericNode for LabelNode {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+            }
+        }
+
+        fn labels(app: &mut App) -> Vec<&'static str> {
+            app.world_mut()
+                .query::<&MavericNodeComponent<LabelNode>>()
+                .iter(app.world())
+                .map(|x| x.node.0)
+                .collect()
+        }
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Label>();
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(labels(&mut app), Vec::<&str>::new());
+
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<Label>>()
+            .add(Label("hello"));
+        app.update();
+        app.update();
+        assert_eq!(labels(&mut app), vec!["hello"]);
+
+        app.world_mut()
+            .resource_mut::<Assets<Label>>()
+            .get_mut(&handle)
+            .unwrap()
+            .0 = "goodbye";
+        app.update();
+        app.update();
+        assert_eq!(labels(&mut app), vec!["goodbye"]);
+    }
+}
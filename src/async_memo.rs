@@ -0,0 +1,292 @@
+use crate::{has_changed::HasChanged, prelude::*};
+use bevy::{
+    ecs::system::{StaticSystemParam, SystemParam},
+    tasks::{AsyncComputeTaskPool, TaskPool},
+};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+/// A value which is expensive to calculate and so is calculated off the main thread.
+///
+/// The argument is read on the main thread to produce an `Input` which is then passed to `calculate` on the `AsyncComputeTaskPool`
+pub trait AsyncMemoValue: Send + Sync + 'static {
+    type Argument<'world, 'state>: MavericContext;
+    type Input: Send + 'static;
+
+    fn input(arg: &<Self::Argument<'_, '_> as SystemParam>::Item<'_, '_>) -> Self::Input;
+
+    fn calculate(input: Self::Input) -> Self;
+}
+
+pub trait CanRegisterAsyncMemo {
+    /// Start calculating this value whenever its argument changes.
+    /// This must be called before `AsyncMemo<T>` can be used
+    fn register_async_memo<T: AsyncMemoValue>(&mut self) -> &mut Self;
+}
+
+impl CanRegisterAsyncMemo for App {
+    fn register_async_memo<T: AsyncMemoValue>(&mut self) -> &mut Self {
+        if self.world().contains_resource::<AsyncMemoResource<T>>() {
+            return self;
+        }
+        self.init_resource::<AsyncMemoResource<T>>();
+        self.add_systems(Last, drive_async_memo::<T>);
+        self
+    }
+}
+
+/// The state of an asynchronous calculation
+#[derive(Debug, PartialEq, Eq)]
+pub enum AsyncMemoStatus<'a, T> {
+    /// The value has not been calculated yet
+    Loading,
+    /// The value is up to date
+    Ready(&'a T),
+    /// The argument has changed and the value is being recalculated
+    Stale(&'a T),
+}
+
+impl<'a, T> AsyncMemoStatus<'a, T> {
+    /// The most recently calculated value, even if it is stale
+    #[must_use]
+    pub const fn value(&self) -> Option<&'a T> {
+        match self {
+            AsyncMemoStatus::Loading => None,
+            AsyncMemoStatus::Ready(value) | AsyncMemoStatus::Stale(value) => Some(value),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_ready(&self) -> bool {
+        matches!(self, AsyncMemoStatus::Ready(..))
+    }
+}
+
+/// The result of an `AsyncMemoValue` calculation.
+/// This is marked as changed when a calculation starts or completes
+#[derive(SystemParam)]
+pub struct AsyncMemo<'w, T: AsyncMemoValue> {
+    resource: Res<'w, AsyncMemoResource<T>>,
+}
+
+impl<T: AsyncMemoValue> AsyncMemo<'_, T> {
+    #[must_use]
+    pub fn status(&self) -> AsyncMemoStatus<'_, T> {
+        self.resource.status()
+    }
+}
+
+impl<T: AsyncMemoValue> HasChanged for AsyncMemo<'_, T> {
+    fn has_changed(&self) -> bool {
+        self.resource.is_changed()
+    }
+}
+
+impl<T: AsyncMemoValue + std::fmt::Debug> std::fmt::Debug for AsyncMemo<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.status().fmt(f)
+    }
+}
+
+impl<T: AsyncMemoValue> Deref for AsyncMemo<'_, T> {
+    type Target = AsyncMemoResource<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// The shared state of an `AsyncMemoValue`.
+/// This is only changed when a calculation starts or completes
+#[derive(Resource)]
+pub struct AsyncMemoResource<T: AsyncMemoValue> {
+    value: Option<T>,
+    loading: bool,
+    started: bool,
+    generation: u64,
+    /// The result of the most recently started calculation to complete, and its generation
+    completed: Arc<Mutex<Option<(u64, T)>>>,
+}
+
+impl<T: AsyncMemoValue> Default for AsyncMemoResource<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            loading: false,
+            started: false,
+            generation: 0,
+            completed: Arc::default(),
+        }
+    }
+}
+
+impl<T: AsyncMemoValue> AsyncMemoResource<T> {
+    #[must_use]
+    pub const fn status(&self) -> AsyncMemoStatus<'_, T> {
+        match (&self.value, self.loading) {
+            (None, _) => AsyncMemoStatus::Loading,
+            (Some(value), false) => AsyncMemoStatus::Ready(value),
+            (Some(value), true) => AsyncMemoStatus::Stale(value),
+        }
+    }
+
+    fn spawn(&mut self, input: T::Input) {
+        self.generation += 1;
+        self.loading = true;
+        self.started = true;
+
+        let generation = self.generation;
+        let completed = self.completed.clone();
+
+        AsyncComputeTaskPool::get_or_init(TaskPool::new)
+            .spawn(async move {
+                let value = T::calculate(input);
+                let Ok(mut completed) = completed.lock() else {
+                    return;
+                };
+                // Do not overwrite the result of a more recent calculation
+                if !matches!(&*completed, Some((g, _)) if *g >= generation) {
+                    *completed = Some((generation, value));
+                }
+            })
+            .detach();
+    }
+
+    /// Take the result of the latest calculation if it has completed
+    fn take_completed(&self) -> Option<T> {
+        let mut completed = self.completed.lock().ok()?;
+        match completed.take() {
+            Some((generation, value)) if generation == self.generation => Some(value),
+            _ => None,
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn drive_async_memo<'w, 's, T: AsyncMemoValue>(
+    param: StaticSystemParam<T::Argument<'w, 's>>,
+    mut resource: ResMut<AsyncMemoResource<T>>,
+) {
+    let inner = param.into_inner();
+
+    if !resource.started || <T::Argument<'w, 's>>::has_item_changed(&inner) {
+        resource.spawn(T::input(&inner));
+    }
+
+    if resource.loading {
+        if let Some(value) = resource.take_completed() {
+            resource.value = Some(value);
+            resource.loading = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default, Resource)]
+    struct Target(u64);
+
+    static TIMES_CALCULATED: AtomicUsize = AtomicUsize::new(0);
+
+    /// The sum of all numbers up to the target
+    #[derive(Debug, PartialEq)]
+    struct Triangle(u64);
+
+    impl AsyncMemoValue for Triangle {
+        type Argument<'world, 'state> = Res<'world, Target>;
+        type Input = u64;
+
+        fn input(
+            arg: &<Self::Argument<'_, '_> as bevy::ecs::system::SystemParam>::Item<'_, '_>,
+        ) -> Self::Input {
+            arg.0
+        }
+
+        fn calculate(input: Self::Input) -> Self {
+            TIMES_CALCULATED.fetch_add(1, Ordering::SeqCst);
+            Self((1..=input).sum())
+        }
+    }
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = AsyncMemo<'w, Triangle>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            let node = match context.status() {
+                AsyncMemoStatus::Loading => ResultNode::Spinner,
+                AsyncMemoStatus::Ready(value) => ResultNode::Value(value.0),
+                AsyncMemoStatus::Stale(value) => ResultNode::StaleValue(value.0),
+            };
+            commands.add_child(0, node, &());
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum ResultNode {
+        Spinner,
+        Value(u64),
+        StaleValue(u64),
+    }
+
+    impl MavericNode for ResultNode {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn result(app: &mut App) -> Option<ResultNode> {
+        app.world_mut()
+            .query::<&MavericNodeComponent<ResultNode>>()
+            .iter(app.world())
+            .map(|x| x.node)
+            .next()
+    }
+
+    /// Update until the value is ready, as the calculation may complete on another thread
+    fn update_until_ready(app: &mut App, expected: u64) {
+        for _ in 0..1000 {
+            app.update();
+            if result(app) == Some(ResultNode::Value(expected)) {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("Async memo was never ready");
+    }
+
+    #[test]
+    pub fn test_async_memo() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.insert_resource(Target(4));
+        app.register_async_memo::<Triangle>();
+        app.register_maveric::<Root>();
+
+        app.update();
+        assert_eq!(result(&mut app), Some(ResultNode::Spinner));
+
+        update_until_ready(&mut app, 10);
+
+        app.world_mut().resource_mut::<Target>().0 = 10;
+        update_until_ready(&mut app, 55);
+
+        // The argument has not changed so the value is not recalculated
+        app.update();
+        app.update();
+        assert_eq!(TIMES_CALCULATED.load(Ordering::SeqCst), 2);
+    }
+}
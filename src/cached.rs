@@ -1,21 +1,63 @@
 use std::{
+    marker::PhantomData,
     ops::Deref,
     sync::{Arc, OnceLock},
+    time::Duration,
 };
 
-use bevy::ecs::system::{ReadOnlySystemParam, SystemParam};
+use bevy::ecs::{
+    system::{ReadOnlySystemParam, SystemParam},
+    world::Command,
+};
 
 use crate::{has_changed::HasChanged, prelude::*};
 
 pub trait CacheableResource: Send + Sync + 'static {
     type Argument<'world, 'state>: SystemParam + ReadOnlySystemParam;
     fn calculate<'w, 's>(arg: &<Self::Argument<'w, 's> as SystemParam>::Item<'w, 's>) -> Self;
+
+    /// How long a calculated value remains valid, measured with `Time`.
+    /// Values do not expire by default
+    #[must_use]
+    fn expiry() -> Option<Duration> {
+        None
+    }
+}
+
+/// A command which forces every `Cached<T>` to recalculate its value
+#[derive(Debug)]
+pub struct InvalidateCached<T: CacheableResource>(PhantomData<T>);
+
+impl<T: CacheableResource> Default for InvalidateCached<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: CacheableResource> Command for InvalidateCached<T> {
+    fn apply(self, world: &mut World) {
+        world
+            .get_resource_or_insert_with(CacheInvalidation::<T>::default)
+            .set_changed();
+    }
+}
+
+/// Changed whenever `Cached<T>` is invalidated
+#[derive(Debug, Resource)]
+pub struct CacheInvalidation<T: CacheableResource>(PhantomData<T>);
+
+impl<T: CacheableResource> Default for CacheInvalidation<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
 }
 
 pub struct Cached<'w, 's, T: CacheableResource> {
     data: Arc<OnceLock<T>>,
     item: <<T as CacheableResource>::Argument<'w, 's> as SystemParam>::Item<'w, 's>,
     previous_data: Arc<OnceLock<T>>,
+    /// Whether the value was invalidated or expired, rather than its argument changing
+    invalidated: bool,
 }
 
 impl<'w, 's, T: CacheableResource> Clone for Cached<'w, 's, T>
@@ -27,6 +69,7 @@ where
             data: self.data.clone(),
             item: self.item.clone(),
             previous_data: self.previous_data.clone(),
+            invalidated: self.invalidated,
         }
     }
 }
@@ -43,7 +86,7 @@ where
         // If the previous cached value was not calculated, then this has changed
         // The the previous cached value was calculated, then compare the two values
 
-        if !self.item.has_changed() {
+        if !self.item.has_changed() && !self.invalidated {
             return false;
         }
 
@@ -82,6 +125,16 @@ impl<'w, 's, T: CacheableResource> Cached<'w, 's, T> {
         let d = self.data.get_or_init(|| T::calculate(&self.item));
         d
     }
+
+    /// The previous value, if this value is being recalculated and the previous value was ever calculated
+    #[must_use]
+    pub fn previous_if_changed(&self) -> Option<&T> {
+        if Arc::ptr_eq(&self.data, &self.previous_data) {
+            None
+        } else {
+            self.previous_data.get()
+        }
+    }
 }
 
 unsafe impl<'w, 's, T: CacheableResource> ReadOnlySystemParam for Cached<'w, 's, T> where
@@ -92,6 +145,10 @@ unsafe impl<'w, 's, T: CacheableResource> ReadOnlySystemParam for Cached<'w, 's,
 pub struct CachedState<T: CacheableResource> {
     pub(crate) inner_state: <T::Argument<'static, 'static> as SystemParam>::State,
     pub(crate) data: Arc<OnceLock<T>>,
+    invalidation_state: <Option<Res<'static, CacheInvalidation<T>>> as SystemParam>::State,
+    time_state: <Option<Res<'static, Time>> as SystemParam>::State,
+    /// The elapsed time when the data was last reset
+    reset_at: Duration,
 }
 
 unsafe impl<'w, 's, T: CacheableResource> SystemParam for Cached<'w, 's, T>
@@ -111,6 +168,13 @@ where
         CachedState {
             inner_state,
             data: Arc::new(OnceLock::new()),
+            invalidation_state:
+                <Option<Res<'static, CacheInvalidation<T>>> as SystemParam>::init_state(
+                    world,
+                    system_meta,
+                ),
+            time_state: <Option<Res<'static, Time>> as SystemParam>::init_state(world, system_meta),
+            reset_at: Duration::ZERO,
         }
     }
 
@@ -130,10 +194,33 @@ where
         let item: <T::Argument<'static, 'static> as SystemParam>::Item<'static, 'static> =
             std::mem::transmute(item);
 
+        let invalidation = <Option<Res<'static, CacheInvalidation<T>>> as SystemParam>::get_param(
+            &mut state.invalidation_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+        let elapsed = <Option<Res<'static, Time>> as SystemParam>::get_param(
+            &mut state.time_state,
+            system_meta,
+            world,
+            change_tick,
+        )
+        .map(|time| time.elapsed());
+
+        let expired = match (T::expiry(), elapsed) {
+            (Some(expiry), Some(elapsed)) => elapsed.saturating_sub(state.reset_at) >= expiry,
+            _ => false,
+        };
+        let invalidated = expired || invalidation.is_some_and(|x| x.is_changed());
+
         let previous_data: Arc<OnceLock<T>> = state.data.clone();
 
-        if item.has_changed() {
+        if item.has_changed() || invalidated {
             state.data = Default::default();
+            if let Some(elapsed) = elapsed {
+                state.reset_at = elapsed;
+            }
         }
 
         let item: <T::Argument<'world, 'state> as SystemParam>::Item<'world, 'state> =
@@ -143,6 +230,7 @@ where
             item,
             data: state.data.clone(),
             previous_data,
+            invalidated,
         }
     }
 }
@@ -153,10 +241,13 @@ pub mod tests {
 
     use bevy::prelude::*;
 
+    use std::time::Duration;
+
     use crate::{
-        cached::{CacheableResource, Cached, CanRegisterMaveric},
+        cached::{CacheableResource, Cached, CanRegisterMaveric, InvalidateCached},
         has_changed::HasChanged,
         root::MavericRoot,
+        untracked::Untracked,
     };
 
     #[test]
@@ -286,6 +377,127 @@ pub mod tests {
 
         assert_times_updated(3);
     }
+    #[test]
+    pub fn test_invalidation_and_expiry() {
+        #[derive(Debug, PartialEq)]
+        pub struct CounterLabel(String);
+
+        static TIMES_UPDATED: AtomicUsize = AtomicUsize::new(0);
+
+        fn assert_times_updated(expected: usize, message: &'static str) {
+            let v = TIMES_UPDATED.load(std::sync::atomic::Ordering::SeqCst);
+            assert_eq!(v, expected, "{message}");
+        }
+
+        impl CacheableResource for CounterLabel {
+            type Argument<'world, 'state> = Res<'world, Counter>;
+
+            fn calculate<'w, 's>(
+                arg: &<Self::Argument<'w, 's> as bevy::ecs::system::SystemParam>::Item<'w, 's>,
+            ) -> Self {
+                TIMES_UPDATED.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Self(format!("Count: {}", arg.0))
+            }
+
+            fn expiry() -> Option<Duration> {
+                Some(Duration::from_secs(10))
+            }
+        }
+
+        fn read_label(label: Cached<CounterLabel>) {
+            assert!(label.0.starts_with("Count"));
+        }
+
+        let mut app = App::new();
+
+        app.init_resource::<Counter>();
+        app.init_resource::<Time>();
+        app.add_systems(Update, read_label);
+
+        app.update();
+        assert_times_updated(1, "Calculated on first run");
+        app.update();
+        assert_times_updated(1, "Nothing has changed");
+
+        app.world_mut()
+            .commands()
+            .add(InvalidateCached::<CounterLabel>::default());
+        app.update();
+        assert_times_updated(2, "The cache was invalidated");
+        app.update();
+        assert_times_updated(2, "The invalidation only applies once");
+
+        advance_time(&mut app, 5);
+        app.update();
+        assert_times_updated(2, "The value has not expired yet");
+
+        advance_time(&mut app, 5);
+        app.update();
+        assert_times_updated(3, "The value has expired");
+
+        advance_time(&mut app, 5);
+        app.update();
+        assert_times_updated(3, "The expiry restarts after recalculating");
+
+        fn advance_time(app: &mut App, seconds: u64) {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(seconds));
+        }
+    }
+
+    #[test]
+    pub fn test_untracked_and_previous() {
+        #[derive(Debug, Resource, Default, PartialEq, Eq)]
+        pub struct Multiplier(usize);
+
+        #[derive(Debug, PartialEq)]
+        pub struct CounterProduct(usize);
+
+        impl CacheableResource for CounterProduct {
+            type Argument<'world, 'state> = (
+                Res<'world, Counter>,
+                Untracked<'world, 'state, Res<'static, Multiplier>>,
+            );
+
+            fn calculate<'w, 's>(
+                arg: &<Self::Argument<'w, 's> as bevy::ecs::system::SystemParam>::Item<'w, 's>,
+            ) -> Self {
+                Self(arg.0 .0 * arg.1 .0)
+            }
+        }
+
+        #[derive(Debug, Resource, Default, PartialEq)]
+        struct Products(Vec<(usize, Option<usize>)>);
+
+        fn record_product(product: Cached<CounterProduct>, mut products: ResMut<Products>) {
+            let previous = product.previous_if_changed().map(|x| x.0);
+            products.0.push((product.0, previous));
+        }
+
+        let mut app = App::new();
+
+        app.init_resource::<Counter>();
+        app.insert_resource(Multiplier(2));
+        app.init_resource::<Products>();
+        app.add_systems(Update, record_product);
+
+        set_count(&mut app, 1);
+        app.update();
+
+        // Changing untracked data does not cause a recalculation
+        app.world_mut().resource_mut::<Multiplier>().0 = 3;
+        app.update();
+
+        set_count(&mut app, 2);
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Products>().0,
+            vec![(2, None), (2, None), (6, Some(2))]
+        );
+    }
+
     fn set_count(app: &mut App, new_count: usize) {
         let mut r = app.world_mut().resource_mut::<Counter>();
 
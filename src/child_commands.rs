@@ -22,35 +22,22 @@ pub trait ChildCommands {
     fn remove_child(&mut self, key: impl Into<ChildKey>);
 }
 
-#[derive(Debug, Default)]
-struct DuplicateChecker {
-    #[cfg(debug_assertions)]
-    set: bevy::utils::HashSet<ChildKey>,
-}
-
-impl DuplicateChecker {
-    #[inline]
-    #[allow(clippy::used_underscore_binding)]
-    pub(crate) fn test(&mut self, _key: ChildKey) {
-        #[cfg(debug_assertions)]
-        {
-            assert!(self.set.insert(_key), "Duplicate Child Key {_key}");
-        }
-    }
-}
-
 pub struct UnorderedChildCommands<'c, 'a, 'world, 'alloc, R: MavericRoot> {
     ec: &'c mut EntityCommands<'a>,
     world: &'world World,
     remaining_old_entities: HashMap<ChildKey, Entity, DefaultHashBuilder, &'alloc Allocator>,
     phantom: PhantomData<R>,
-    duplicate_checker: DuplicateChecker,
+    duplicate_checker: DuplicateChecker<'alloc>,
+    held_children: HeldChildren<'alloc, Entity>,
+    ambient: Ambient<'c>,
 }
 
 impl<'c, 'a, 'world, 'alloc, R: MavericRoot> Drop
     for UnorderedChildCommands<'c, 'a, 'world, 'alloc, R>
 {
     fn drop(&mut self) {
+        self.held_children.release(&mut self.ec.commands());
+
         //remove all remaining old entities
         for (_key, entity) in &self.remaining_old_entities {
             let _ = delete_recursive::<R>(&mut self.ec.commands(), *entity, self.world);
@@ -64,10 +51,23 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> ChildCommands
     fn remove_child(&mut self, key: impl Into<ChildKey>) {
         let key: ChildKey = key.into();
 
-        self.duplicate_checker.test(key);
+        let key = match self.duplicate_checker.check::<R>(
+            key,
+            None,
+            Some(self.ec.id()),
+            &mut self.ec.commands(),
+        ) {
+            KeyCheck::Use(key) => key,
+            KeyCheck::Skip => return,
+            KeyCheck::ReplacePrevious(_) => {
+                self.discard_previous(key);
+                key
+            }
+        };
+        self.duplicate_checker.record(key, Entity::PLACEHOLDER);
 
         if let Some(entity) = self.remaining_old_entities.remove(&key) {
-            self.ec.commands().entity(entity).despawn_recursive();
+            despawn_managed(&mut self.ec.commands(), entity);
         }
     }
 
@@ -79,45 +79,46 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> ChildCommands
     ) {
         let key = key.into();
 
-        self.duplicate_checker.test(key);
-
-        if let Some(entity) = self.remaining_old_entities.remove(&key) {
-            //check if this node has changed
-
-            if let Some(previous) = self.world.get::<MavericNodeComponent<NChild>>(entity) {
-                if !child.should_recreate(&previous.node, context) {
-                    update_recursive::<R, NChild>(
-                        &mut self.ec.commands(),
-                        entity,
-                        child,
-                        context,
-                        self.world,
-                        self.remaining_old_entities.allocator(),
-                    );
-                    return; // do not spawn a new child;
-                }
-            } else {
-                warn!(
-                    "Child with key '{key}' has had node type changed to {}",
-                    type_name::<NChild>()
-                );
-                // The node type has changed - delete this entity and readd
+        let key = match self.duplicate_checker.check::<R>(
+            key,
+            Some(type_name::<NChild>()),
+            Some(self.ec.id()),
+            &mut self.ec.commands(),
+        ) {
+            KeyCheck::Use(key) => key,
+            KeyCheck::Skip => return,
+            KeyCheck::ReplacePrevious(_) => {
+                self.discard_previous(key);
+                key
             }
+        };
 
-            self.ec.commands().entity(entity).despawn_recursive();
-        }
-
-        self.ec.with_children(|cb| {
-            let cec = cb.spawn_empty();
-            create_recursive::<R, NChild>(
-                cec,
+        let id = if self.duplicate_checker.holds_children() {
+            let parent = self.ec.id();
+            let old = self.remaining_old_entities.get(&key).copied();
+            self.held_children.hold(key, old, self.world, |commands| {
+                Self::add_child_to(
+                    &mut commands.entity(parent),
+                    self.world,
+                    &mut self.remaining_old_entities,
+                    self.ambient,
+                    key,
+                    child,
+                    context,
+                )
+            })
+        } else {
+            Self::add_child_to(
+                self.ec,
+                self.world,
+                &mut self.remaining_old_entities,
+                self.ambient,
+                key,
                 child,
                 context,
-                key,
-                self.world,
-                self.remaining_old_entities.allocator(),
-            );
-        });
+            )
+        };
+        self.duplicate_checker.record(key, id);
     }
 }
 
@@ -126,6 +127,7 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> UnorderedChildCommands<'c, 'a, 'wor
         ec: &'c mut EntityCommands<'a>,
         world: &'world World,
         allocator: &'alloc Allocator,
+        ambient: Ambient<'c>,
     ) -> Self {
         let children = world.get::<Children>(ec.id());
         let child_count = children.map(|x| x.len()).unwrap_or_default();
@@ -144,9 +146,76 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> UnorderedChildCommands<'c, 'a, 'wor
             world,
             remaining_old_entities,
             phantom: PhantomData,
-            duplicate_checker: DuplicateChecker::default(),
+            duplicate_checker: DuplicateChecker::new(world, allocator),
+            held_children: HeldChildren::new(allocator),
+            ambient,
+        }
+    }
+
+    /// Discard the held child which previously had this key so that its existing entity can be reused
+    fn discard_previous(&mut self, key: ChildKey) {
+        if let Some(entity) = self.held_children.discard(key, &mut self.ec.commands()) {
+            self.remaining_old_entities.insert(key, entity);
         }
     }
+
+    /// Update the existing child with this key or create a new one, returning its entity
+    fn add_child_to<NChild: MavericNode>(
+        ec: &mut EntityCommands,
+        world: &World,
+        remaining_old_entities: &mut HashMap<
+            ChildKey,
+            Entity,
+            DefaultHashBuilder,
+            &'alloc Allocator,
+        >,
+        ambient: Ambient,
+        key: ChildKey,
+        child: NChild,
+        context: &NChild::Context<'_, '_>,
+    ) -> Entity {
+        if let Some(entity) = remaining_old_entities.remove(&key) {
+            //check if this node has changed
+
+            if let Some(previous) = world.get::<MavericNodeComponent<NChild>>(entity) {
+                if !child.should_recreate(&previous.node, context) {
+                    update_recursive::<R, NChild>(
+                        &mut ec.commands(),
+                        entity,
+                        child,
+                        context,
+                        ambient,
+                        world,
+                        remaining_old_entities.allocator(),
+                    );
+                    return entity; // do not spawn a new child;
+                }
+            } else {
+                warn!(
+                    "Child with key '{key}' has had node type changed to {}",
+                    type_name::<NChild>()
+                );
+                // The node type has changed - delete this entity and readd
+            }
+
+            despawn_managed(&mut ec.commands(), entity);
+        }
+
+        let mut id = Entity::PLACEHOLDER;
+        ec.with_children(|cb| {
+            let cec = cb.spawn_empty();
+            id = create_recursive::<R, NChild>(
+                cec,
+                child,
+                context,
+                ambient,
+                key,
+                world,
+                remaining_old_entities.allocator(),
+            );
+        });
+        id
+    }
 }
 
 pub struct OrderedChildCommands<'c, 'a, 'world, 'alloc, R: MavericRoot> {
@@ -157,13 +226,17 @@ pub struct OrderedChildCommands<'c, 'a, 'world, 'alloc, R: MavericRoot> {
         HashMap<ChildKey, (usize, Entity), DefaultHashBuilder, &'alloc Allocator>,
     new_children: allocator_api2::vec::Vec<Entity, &'alloc Allocator>,
     new_indices: allocator_api2::vec::Vec<Option<usize>, &'alloc Allocator>,
-    duplicate_checker: DuplicateChecker,
+    duplicate_checker: DuplicateChecker<'alloc>,
+    held_children: HeldChildren<'alloc, (usize, Entity)>,
+    ambient: Ambient<'c>,
 }
 
 impl<'c, 'a, 'world, 'alloc, R: MavericRoot> Drop
     for OrderedChildCommands<'c, 'a, 'world, 'alloc, R>
 {
     fn drop(&mut self) {
+        self.held_children.release(&mut self.ec.commands());
+
         let order_changed = {
             let mut changed = false;
             let mut last = 0;
@@ -237,10 +310,23 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> ChildCommands
     fn remove_child(&mut self, key: impl Into<ChildKey>) {
         let key: ChildKey = key.into();
 
-        self.duplicate_checker.test(key);
+        let key = match self.duplicate_checker.check::<R>(
+            key,
+            None,
+            Some(self.ec.id()),
+            &mut self.ec.commands(),
+        ) {
+            KeyCheck::Use(key) => key,
+            KeyCheck::Skip => return,
+            KeyCheck::ReplacePrevious(previous_entity) => {
+                self.discard_previous(key, previous_entity);
+                key
+            }
+        };
+        self.duplicate_checker.record(key, Entity::PLACEHOLDER);
 
         if let Some((_index, entity)) = self.remaining_old_entities.remove(&key) {
-            self.ec.commands().entity(entity).despawn_recursive();
+            despawn_managed(&mut self.ec.commands(), entity);
         }
     }
 
@@ -252,49 +338,47 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> ChildCommands
     ) {
         let key = key.into();
 
-        self.duplicate_checker.test(key);
-
-        if let Some((old_index, entity)) = self.remaining_old_entities.remove(&key) {
-            //check if this node has changed
-
-            if let Some(previous) = self.world.get::<MavericNodeComponent<NChild>>(entity) {
-                if !child.should_recreate(&previous.node, context) {
-                    update_recursive::<R, NChild>(
-                        &mut self.ec.commands(),
-                        entity,
-                        child,
-                        context,
-                        self.world,
-                        self.remaining_old_entities.allocator(),
-                    );
-                    self.new_children.push(entity);
-                    self.new_indices.push(Some(old_index));
-                    return; //do not spawn a new child
-                }
-            } else {
-                warn!(
-                    "Child with key '{key}' has had node type changed to {}",
-                    type_name::<NChild>()
-                );
-                // The node type has changed - delete this entity and readd
+        let key = match self.duplicate_checker.check::<R>(
+            key,
+            Some(type_name::<NChild>()),
+            Some(self.ec.id()),
+            &mut self.ec.commands(),
+        ) {
+            KeyCheck::Use(key) => key,
+            KeyCheck::Skip => return,
+            KeyCheck::ReplacePrevious(previous_entity) => {
+                self.discard_previous(key, previous_entity);
+                key
             }
-
-            // Delete and readd
-            self.ec.commands().entity(entity).despawn_recursive();
         };
-        let mut commands = self.ec.commands();
 
-        let new_commands = commands.spawn_empty();
-        let id = create_recursive::<R, NChild>(
-            new_commands,
-            child,
-            context,
-            key,
-            self.world,
-            self.remaining_old_entities.allocator(),
-        );
+        let (id, old_index) = if self.duplicate_checker.holds_children() {
+            let old = self.remaining_old_entities.get(&key).copied();
+            self.held_children.hold(key, old, self.world, |commands| {
+                Self::add_child_to(
+                    commands,
+                    self.world,
+                    &mut self.remaining_old_entities,
+                    self.ambient,
+                    key,
+                    child,
+                    context,
+                )
+            })
+        } else {
+            Self::add_child_to(
+                &mut self.ec.commands(),
+                self.world,
+                &mut self.remaining_old_entities,
+                self.ambient,
+                key,
+                child,
+                context,
+            )
+        };
         self.new_children.push(id);
-        self.new_indices.push(None);
+        self.new_indices.push(old_index);
+        self.duplicate_checker.record(key, id);
     }
 }
 
@@ -303,6 +387,7 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> OrderedChildCommands<'c, 'a, 'world
         ec: &'c mut EntityCommands<'a>,
         world: &'world World,
         allocator: &'alloc Allocator,
+        ambient: Ambient<'c>,
     ) -> Self {
         let children = world.get::<Children>(ec.id());
         let child_count = children.map(|x| x.len()).unwrap_or_default();
@@ -327,9 +412,79 @@ impl<'c, 'a, 'world, 'alloc, R: MavericRoot> OrderedChildCommands<'c, 'a, 'world
             phantom: PhantomData,
             new_children: allocator_api2::vec::Vec::new_in(allocator),
             new_indices: allocator_api2::vec::Vec::new_in(allocator),
-            duplicate_checker: DuplicateChecker::default(),
+            duplicate_checker: DuplicateChecker::new(world, allocator),
+            held_children: HeldChildren::new(allocator),
+            ambient,
+        }
+    }
+
+    /// Discard the held child which previously had this key so that its existing entity can be reused
+    fn discard_previous(&mut self, key: ChildKey, previous_entity: Entity) {
+        if let Some(index) = self.new_children.iter().position(|x| *x == previous_entity) {
+            self.new_children.remove(index);
+            self.new_indices.remove(index);
+        }
+        if let Some(old) = self.held_children.discard(key, &mut self.ec.commands()) {
+            self.remaining_old_entities.insert(key, old);
         }
     }
+
+    /// Update the existing child with this key or create a new one.
+    /// Returns its entity and its old index if it was updated
+    fn add_child_to<NChild: MavericNode>(
+        commands: &mut Commands,
+        world: &World,
+        remaining_old_entities: &mut HashMap<
+            ChildKey,
+            (usize, Entity),
+            DefaultHashBuilder,
+            &'alloc Allocator,
+        >,
+        ambient: Ambient,
+        key: ChildKey,
+        child: NChild,
+        context: &NChild::Context<'_, '_>,
+    ) -> (Entity, Option<usize>) {
+        if let Some((old_index, entity)) = remaining_old_entities.remove(&key) {
+            //check if this node has changed
+
+            if let Some(previous) = world.get::<MavericNodeComponent<NChild>>(entity) {
+                if !child.should_recreate(&previous.node, context) {
+                    update_recursive::<R, NChild>(
+                        commands,
+                        entity,
+                        child,
+                        context,
+                        ambient,
+                        world,
+                        remaining_old_entities.allocator(),
+                    );
+                    return (entity, Some(old_index)); //do not spawn a new child
+                }
+            } else {
+                warn!(
+                    "Child with key '{key}' has had node type changed to {}",
+                    type_name::<NChild>()
+                );
+                // The node type has changed - delete this entity and readd
+            }
+
+            // Delete and readd
+            despawn_managed(commands, entity);
+        };
+
+        let new_commands = commands.spawn_empty();
+        let id = create_recursive::<R, NChild>(
+            new_commands,
+            child,
+            context,
+            ambient,
+            key,
+            world,
+            remaining_old_entities.allocator(),
+        );
+        (id, None)
+    }
 }
 
 #[cfg(test)]
@@ -409,6 +564,66 @@ mod tests {
         app.update();
     }
 
+    #[test]
+    pub fn test_duplicate_key_keep_first() {
+        test_duplicate_key_policy(DuplicateKeyPolicy::KeepFirst, vec![(1, false), (2, false)]);
+    }
+
+    #[test]
+    pub fn test_duplicate_key_keep_last() {
+        let mut app =
+            test_duplicate_key_policy(DuplicateKeyPolicy::KeepLast, vec![(2, false), (1, false)]);
+
+        let entities = get_leaf_entities(&mut app);
+
+        // The kept child should keep its entity rather than being recreated on every update
+        for _ in 0..2 {
+            app.world_mut().resource_mut::<TreeState>().set_changed();
+            app.update();
+
+            assert_eq!(get_leaves(&mut app), vec![(2, false), (1, false)]);
+            assert_eq!(get_leaf_entities(&mut app), entities);
+        }
+    }
+
+    #[test]
+    pub fn test_duplicate_key_auto_suffix() {
+        test_duplicate_key_policy(
+            DuplicateKeyPolicy::AutoSuffix,
+            vec![(1, false), (2, false), (1, false)],
+        );
+    }
+
+    fn test_duplicate_key_policy(policy: DuplicateKeyPolicy, expected: Vec<(u32, bool)>) -> App {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+
+        app.insert_resource(policy)
+            .init_resource::<TreeState>()
+            .init_resource::<LingerState>()
+            .register_maveric::<Root>();
+
+        update_state(&mut app, TreeState(vec![1, 2, 1]));
+        app.update();
+
+        let leaves: Vec<(u32, bool)> = get_leaves(&mut app);
+        assert_eq!(leaves, expected);
+
+        let events = app.world().resource::<Events<DuplicateKeyEvent>>();
+        let events: Vec<_> = events.get_reader().read(events).cloned().collect();
+
+        assert_eq!(
+            events,
+            vec![DuplicateKeyEvent {
+                key_path: vec!["branch".into(), 1u32.into()],
+                node_type: Some(std::any::type_name::<Leaf>()),
+                policy
+            }]
+        );
+
+        app
+    }
+
     fn test_linger(
         initial_tree_state: TreeState,
         linger_state: LingerState,
@@ -443,6 +658,13 @@ mod tests {
         assert_eq!(leaves, expected, "test case {test_index}");
     }
 
+    fn get_leaf_entities(app: &mut App) -> Vec<Entity> {
+        app.world_mut()
+            .query_filtered::<&Children, With<MavericNodeComponent<Branch>>>()
+            .single(app.world())
+            .to_vec()
+    }
+
     fn get_leaves(app: &mut App) -> Vec<(u32, bool)> {
         let children = app
             .world_mut()
@@ -21,6 +21,21 @@ impl std::fmt::Display for NumberKey {
 pub enum ChildKey {
     String(&'static str),
     Number(NumberKey),
+    /// A string key which was made unique by `DuplicateKeyPolicy::AutoSuffix`
+    SuffixedString(&'static str, u16),
+    /// A number key which was made unique by `DuplicateKeyPolicy::AutoSuffix`
+    SuffixedNumber(NumberKey, u16),
+}
+
+impl ChildKey {
+    /// Returns this key with the suffix replaced
+    #[must_use]
+    pub const fn with_suffix(self, suffix: u16) -> Self {
+        match self {
+            Self::String(s) | Self::SuffixedString(s, _) => Self::SuffixedString(s, suffix),
+            Self::Number(n) | Self::SuffixedNumber(n, _) => Self::SuffixedNumber(n, suffix),
+        }
+    }
 }
 
 impl std::fmt::Display for ChildKey {
@@ -28,6 +43,8 @@ impl std::fmt::Display for ChildKey {
         match self {
             Self::Number(u) => u.fmt(f),
             Self::String(s) => s.fmt(f),
+            Self::SuffixedNumber(u, suffix) => write!(f, "{u}#{suffix}"),
+            Self::SuffixedString(s, suffix) => write!(f, "{s}#{suffix}"),
         }
     }
 }
@@ -30,7 +30,7 @@ impl<'c, 'a, 'world> ComponentCommands<'c, 'a, 'world> {
     }
 
     pub fn insert<T: Bundle>(&mut self, bundle: T) {
-        self.ec.insert(bundle);
+        self.ec.try_insert(bundle);
     }
 
     pub fn remove<T: Bundle>(&mut self) {
@@ -47,6 +47,27 @@ impl<'c, 'a, 'world> ComponentCommands<'c, 'a, 'world> {
         self.world.get_resource()
     }
 
+    /// Gets a resource, reporting a `MavericError` if it is missing.
+    /// This resource usage is not tracked, meaning changes to this resource will not result in recalculating components
+    pub fn get_res_or_report<R: Resource>(&mut self) -> Option<&'world R> {
+        let resource = self.world.get_resource();
+        if resource.is_none() {
+            self.report_error(MavericErrorKind::MissingResource(std::any::type_name::<R>()));
+        }
+        resource
+    }
+
+    /// Report an error which the node has recovered from.
+    /// This will log a warning and send a `MavericError` event
+    pub fn report_error(&mut self, kind: MavericErrorKind) {
+        let error = MavericError {
+            entity: self.ec.id(),
+            kind,
+        };
+
+        send_error(&mut self.ec.commands(), error);
+    }
+
     /// Insert a resource into the world.
     /// You probably shouldn't use this unless you know what you are doing but it can be useful in implementing `on_deleted`
     pub fn insert_resource<R: Resource>(&mut self, resource: R) {
@@ -66,8 +87,9 @@ impl<'c, 'a, 'world> ComponentCommands<'c, 'a, 'world> {
         for child_entity in children {
             if let Some(child) = self.world.get_entity(*child_entity) {
                 let mut commands = self.ec.commands();
-                let child_ec = commands.entity(*child_entity);
-                action(child, child_ec);
+                if let Some(child_ec) = commands.get_entity(*child_entity) {
+                    action(child, child_ec);
+                }
             }
         }
     }
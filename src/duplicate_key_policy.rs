@@ -0,0 +1,330 @@
+use crate::{error_boundary::guard, prelude::*};
+use bevy::{
+    ecs::world::CommandQueue,
+    utils::hashbrown::{hash_map::DefaultHashBuilder, HashMap},
+};
+
+/// What to do when a node adds two children with the same key.
+/// Insert this as a resource to change the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub enum DuplicateKeyPolicy {
+    /// Panic. This is the default in debug mode
+    Panic,
+    /// Log a warning and ignore every child after the first. This is the default in release mode
+    KeepFirst,
+    /// Log a warning and replace the earlier child with the later one
+    KeepLast,
+    /// Log a warning and give the later child a new key with a numeric suffix.
+    /// If every suffix has been used the later child is ignored, as with `KeepFirst`
+    AutoSuffix,
+}
+
+impl Default for DuplicateKeyPolicy {
+    fn default() -> Self {
+        if cfg!(debug_assertions) {
+            Self::Panic
+        } else {
+            Self::KeepFirst
+        }
+    }
+}
+
+/// Sent whenever a duplicate child key is found.
+/// These events are sent in `PreUpdate`
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct DuplicateKeyEvent {
+    /// The keys of the child's ancestors, starting at the root and ending with the duplicate key
+    pub key_path: Vec<ChildKey>,
+    /// The type of the duplicate node. This will be `None` if the duplicate was a call to `remove_child`
+    pub node_type: Option<&'static str>,
+    /// The policy which was applied
+    pub policy: DuplicateKeyPolicy,
+}
+
+/// The result of checking a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyCheck {
+    /// Use this key for the child
+    Use(ChildKey),
+    /// Do not add the child
+    Skip,
+    /// Discard the held child which previously had the key and then add the child
+    ReplacePrevious(Entity),
+}
+
+pub(crate) struct DuplicateChecker<'alloc> {
+    policy: DuplicateKeyPolicy,
+    keys: HashMap<ChildKey, Entity, DefaultHashBuilder, &'alloc Allocator>,
+}
+
+impl<'alloc> DuplicateChecker<'alloc> {
+    pub(crate) fn new(world: &World, allocator: &'alloc Allocator) -> Self {
+        Self {
+            policy: world
+                .get_resource::<DuplicateKeyPolicy>()
+                .copied()
+                .unwrap_or_default(),
+            keys: HashMap::new_in(allocator),
+        }
+    }
+
+    /// Check whether this key has been used before by this parent
+    pub(crate) fn check<R: MavericRoot>(
+        &self,
+        key: ChildKey,
+        node_type: Option<&'static str>,
+        parent: Option<Entity>,
+        commands: &mut Commands,
+    ) -> KeyCheck {
+        let Some(previous) = self.keys.get(&key).copied() else {
+            return KeyCheck::Use(key);
+        };
+
+        assert!(
+            self.policy != DuplicateKeyPolicy::Panic,
+            "Duplicate Child Key {key}"
+        );
+
+        let (policy, check) = match self.policy {
+            DuplicateKeyPolicy::Panic | DuplicateKeyPolicy::KeepFirst => {
+                (self.policy, KeyCheck::Skip)
+            }
+            DuplicateKeyPolicy::KeepLast => {
+                // The previous use of this key was a call to `remove_child`
+                if previous == Entity::PLACEHOLDER {
+                    (self.policy, KeyCheck::Use(key))
+                } else {
+                    (self.policy, KeyCheck::ReplacePrevious(previous))
+                }
+            }
+            DuplicateKeyPolicy::AutoSuffix => (1..=u16::MAX)
+                .map(|suffix| key.with_suffix(suffix))
+                .find(|suffixed| !self.keys.contains_key(suffixed))
+                .map_or(
+                    // Every suffix has been used so keep the first child
+                    (DuplicateKeyPolicy::KeepFirst, KeyCheck::Skip),
+                    |suffixed| (self.policy, KeyCheck::Use(suffixed)),
+                ),
+        };
+
+        let pending = PendingDuplicateKey {
+            parent,
+            key,
+            node_type,
+            policy,
+            key_path: key_path::<R>,
+        };
+
+        commands.add(move |world: &mut World| {
+            world
+                .get_resource_or_insert_with(PendingDuplicateKeys::default)
+                .0
+                .push(pending);
+        });
+
+        check
+    }
+
+    /// Record that a key has been used for this entity.
+    /// Use `Entity::PLACEHOLDER` for keys which were removed
+    pub(crate) fn record(&mut self, key: ChildKey, entity: Entity) {
+        self.keys.insert(key, entity);
+    }
+
+    /// Whether children must be held until they are all added, as a later child may replace an earlier one
+    pub(crate) fn holds_children(&self) -> bool {
+        self.policy == DuplicateKeyPolicy::KeepLast
+    }
+}
+
+/// The commands for children which may still be replaced by a later child with the same key.
+/// Holding them back means the entity for the key is only updated once, by the child which is kept.
+/// `Old` is how the parent remembers its existing children.
+pub(crate) struct HeldChildren<'alloc, Old> {
+    children: allocator_api2::vec::Vec<HeldChild<Old>, &'alloc Allocator>,
+}
+
+struct HeldChild<Old> {
+    key: ChildKey,
+    /// The existing child which was taken for this key
+    old: Option<Old>,
+    queue: CommandQueue,
+    /// Entities which were reserved while adding the child
+    created: Vec<Entity>,
+}
+
+impl<'alloc, Old> HeldChildren<'alloc, Old> {
+    pub(crate) const fn new(allocator: &'alloc Allocator) -> Self {
+        Self {
+            children: allocator_api2::vec::Vec::new_in(allocator),
+        }
+    }
+
+    /// Add a child using commands which are held back until `release` is called
+    pub(crate) fn hold<Out>(
+        &mut self,
+        key: ChildKey,
+        old: Option<Old>,
+        world: &World,
+        add: impl FnOnce(&mut Commands) -> Out,
+    ) -> Out {
+        let mut queue = CommandQueue::default();
+        let (out, created) = {
+            let mut commands = Commands::new_from_entities(&mut queue, world.entities());
+            guard(|| add(&mut commands))
+        };
+        self.children.push(HeldChild {
+            key,
+            old,
+            queue,
+            created,
+        });
+        out
+    }
+
+    /// Discard the commands for the child which had this key.
+    /// Returns the existing child it took so that it can be given to the next child with the key
+    pub(crate) fn discard(&mut self, key: ChildKey, commands: &mut Commands) -> Option<Old> {
+        let index = self.children.iter().rposition(|x| x.key == key)?;
+        let child = self.children.remove(index);
+
+        // These were reserved but their commands were discarded, so they would be left empty
+        for entity in child.created {
+            commands.entity(entity).despawn();
+        }
+
+        child.old
+    }
+
+    /// Apply the commands for every child which was kept
+    pub(crate) fn release(&mut self, commands: &mut Commands) {
+        for mut child in self.children.drain(..) {
+            commands.append(&mut child.queue);
+        }
+    }
+}
+
+/// Sends events for duplicate keys found when the tree was synced.
+/// This runs after the tree commands have been applied so that the full key path can be found
+pub(crate) struct DuplicateKeyPlugin;
+
+impl Plugin for DuplicateKeyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DuplicateKeyEvent>()
+            .init_resource::<PendingDuplicateKeys>()
+            .add_systems(
+                PreUpdate,
+                send_duplicate_key_events
+                    .run_if(|pending: Res<PendingDuplicateKeys>| !pending.0.is_empty()),
+            );
+    }
+}
+
+#[derive(Debug, Default, Resource)]
+struct PendingDuplicateKeys(Vec<PendingDuplicateKey>);
+
+#[derive(Debug)]
+struct PendingDuplicateKey {
+    parent: Option<Entity>,
+    key: ChildKey,
+    node_type: Option<&'static str>,
+    policy: DuplicateKeyPolicy,
+    key_path: fn(Option<Entity>, ChildKey, &World) -> Vec<ChildKey>,
+}
+
+fn send_duplicate_key_events(world: &mut World) {
+    let pending = std::mem::take(&mut world.resource_mut::<PendingDuplicateKeys>().0);
+
+    for pending in pending {
+        let event = DuplicateKeyEvent {
+            key_path: (pending.key_path)(pending.parent, pending.key, world),
+            node_type: pending.node_type,
+            policy: pending.policy,
+        };
+
+        warn!(
+            "Duplicate Child Key {} for {}",
+            DisplayPath(&event.key_path),
+            event.node_type.unwrap_or("removed child")
+        );
+
+        world.send_event(event);
+    }
+}
+
+fn key_path<R: MavericRoot>(parent: Option<Entity>, key: ChildKey, world: &World) -> Vec<ChildKey> {
+    let mut path = vec![key];
+    let mut current = parent;
+
+    while let Some(entity) = current {
+        if let Some(child_component) = world.get::<MavericChildComponent<R>>(entity) {
+            path.push(child_component.key);
+        }
+        current = world.get::<Parent>(entity).map(Parent::get);
+    }
+
+    path.reverse();
+    path
+}
+
+struct DisplayPath<'a>(&'a [ChildKey]);
+
+impl std::fmt::Display for DisplayPath<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, key) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str("/")?;
+            }
+            key.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::world::CommandQueue;
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = ();
+
+        fn set_children(_context: &Self::Context<'_, '_>, _commands: &mut impl ChildCommands) {}
+    }
+
+    #[test]
+    pub fn test_auto_suffix_exhausted() {
+        let mut world = World::new();
+        world.insert_resource(DuplicateKeyPolicy::AutoSuffix);
+        let allocator = Allocator::default();
+        let mut checker = DuplicateChecker::new(&world, &allocator);
+
+        let key = ChildKey::from(1u32);
+        checker.record(key, Entity::PLACEHOLDER);
+        for suffix in 1..u16::MAX {
+            checker.record(key.with_suffix(suffix), Entity::PLACEHOLDER);
+        }
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        assert_eq!(
+            checker.check::<Root>(key, None, None, &mut commands),
+            KeyCheck::Use(key.with_suffix(u16::MAX))
+        );
+
+        checker.record(key.with_suffix(u16::MAX), Entity::PLACEHOLDER);
+        assert_eq!(
+            checker.check::<Root>(key, None, None, &mut commands),
+            KeyCheck::Skip,
+            "The child should be skipped rather than overflowing the suffix"
+        );
+
+        queue.apply(&mut world);
+        let pending = &world.resource::<PendingDuplicateKeys>().0;
+        assert_eq!(pending[0].policy, DuplicateKeyPolicy::AutoSuffix);
+        assert_eq!(pending[1].policy, DuplicateKeyPolicy::KeepFirst);
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    cell::RefCell,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use bevy::ecs::world::CommandQueue;
+
+use crate::prelude::*;
+
+/// Contains panics in a subtree.
+///
+/// The node is added as a child of the boundary and its commands are only applied if it does not panic.
+/// If it does panic, the node's subtree is despawned, the fallback is shown in its place and a `MavericError` is sent.
+/// The node will be tried again the next time the boundary changes.
+///
+/// The boundary entity has no components of its own - use `with_bundle` to give it some.
+/// Entities which were created before the panic are despawned.
+/// This does nothing if panics are set to abort.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorBoundary<N: MavericNode + Clone, Fallback: MavericNode + Clone> {
+    pub node: N,
+    pub fallback: Fallback,
+}
+
+impl<N: MavericNode + Clone, Fallback: MavericNode + Clone> ErrorBoundary<N, Fallback> {
+    pub const fn new(node: N, fallback: Fallback) -> Self {
+        Self { node, fallback }
+    }
+}
+
+thread_local! {
+    /// The entities created inside each boundary which is being set, innermost last
+    static GUARDED_ENTITIES: RefCell<Vec<Vec<Entity>>> = const { RefCell::new(vec![]) };
+}
+
+/// Record an entity so that it can be despawned if the boundary it was created in catches a panic
+pub(crate) fn record_created(entity: Entity) {
+    GUARDED_ENTITIES.with_borrow_mut(|stack| {
+        if let Some(entities) = stack.last_mut() {
+            entities.push(entity);
+        }
+    });
+}
+
+/// Run `f`, returning the entities which were created while it ran
+pub(crate) fn guard<Out>(f: impl FnOnce() -> Out) -> (Out, Vec<Entity>) {
+    GUARDED_ENTITIES.with_borrow_mut(|stack| stack.push(vec![]));
+    let frame = GuardFrame;
+    let out = f();
+    std::mem::forget(frame);
+    (out, pop_guarded_entities())
+}
+
+/// Pops the frame if `f` panics, so that the boundary which catches the panic finds its own frame
+struct GuardFrame;
+
+impl Drop for GuardFrame {
+    fn drop(&mut self) {
+        pop_guarded_entities();
+    }
+}
+
+fn pop_guarded_entities() -> Vec<Entity> {
+    GUARDED_ENTITIES.with_borrow_mut(|stack| {
+        let created = stack.pop().unwrap_or_default();
+        // An enclosing boundary must also despawn these if it catches a panic
+        if let Some(outer) = stack.last_mut() {
+            outer.extend_from_slice(&created);
+        }
+        created
+    })
+}
+
+const NODE_KEY: ChildKey = ChildKey::String("node");
+const FALLBACK_KEY: ChildKey = ChildKey::String("fallback");
+
+impl<N: MavericNode + Clone, Fallback> MavericNode for ErrorBoundary<N, Fallback>
+where
+    Fallback: Clone + for<'w, 's> MavericNode<Context<'w, 's> = ()>,
+{
+    type Context<'w, 's> = N::Context<'w, 's>;
+
+    fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let (args, world, ec, alloc) = commands.into_parts();
+        if !args.should_visit_children() {
+            return;
+        }
+
+        let boundary = ec.id();
+        let mut queue = CommandQueue::default();
+
+        let (result, created) = {
+            let mut isolated_commands = Commands::new_from_entities(&mut queue, world.entities());
+            let mut isolated_ec = isolated_commands.entity(boundary);
+
+            guard(|| {
+                catch_unwind(AssertUnwindSafe(|| {
+                    let mut child_commands = UnorderedChildCommands::<R>::new(
+                        &mut isolated_ec,
+                        world,
+                        alloc,
+                        args.ambient,
+                    );
+                    child_commands.add_child(NODE_KEY, args.node.node.clone(), args.context);
+                }))
+            })
+        };
+
+        match result {
+            Ok(()) => {
+                ec.commands().append(&mut queue);
+            }
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|x| (*x).to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+
+                // These were reserved but their commands were discarded, so they would be left empty
+                for entity in created {
+                    ec.commands().entity(entity).despawn();
+                }
+
+                let mut child_commands =
+                    UnorderedChildCommands::<R>::new(ec, world, alloc, args.ambient);
+                child_commands.remove_child(NODE_KEY);
+                child_commands.add_child(FALLBACK_KEY, args.node.fallback.clone(), &());
+                drop(child_commands);
+
+                send_error(
+                    &mut ec.commands(),
+                    MavericError {
+                        entity: boundary,
+                        kind: MavericErrorKind::Panic(message),
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GUARDED_ENTITIES;
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    pub fn test_error_boundary() {
+        #[derive(Debug, Default, Resource)]
+        struct Number(u32);
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = Res<'w, Number>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                commands.add_child(0, ErrorBoundary::new(Even(context.0), Fallback), &());
+            }
+        }
+
+        /// Panics if the number is odd
+        #[derive(Debug, Clone, PartialEq)]
+        struct Even(u32);
+
+        impl MavericNode for Even {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+                let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+                    return;
+                };
+                commands.add_child(0, Leaf, &());
+                assert!(node.0 % 2 == 0, "{} is odd", node.0);
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Leaf;
+
+        impl MavericNode for Leaf {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Fallback;
+
+        impl MavericNode for Fallback {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+            }
+        }
+
+        fn count<N: MavericNode>(app: &mut App) -> usize {
+            app.world_mut()
+                .query::<&MavericNodeComponent<N>>()
+                .iter(app.world())
+                .count()
+        }
+
+        fn count_errors(app: &App) -> usize {
+            let events = app.world().resource::<Events<MavericError>>();
+            events
+                .get_reader()
+                .read(events)
+                .filter(|x| matches!(&x.kind, MavericErrorKind::Panic(m) if m == "1 is odd"))
+                .count()
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.init_resource::<Number>();
+        app.register_maveric::<Root>();
+        app.update();
+
+        assert_eq!(count::<Even>(&mut app), 1);
+        assert_eq!(count::<Leaf>(&mut app), 1);
+        assert_eq!(count::<Fallback>(&mut app), 0);
+
+        app.world_mut().resource_mut::<Number>().0 = 1;
+        app.update();
+
+        assert_eq!(count::<Even>(&mut app), 0);
+        assert_eq!(count::<Leaf>(&mut app), 0);
+        assert_eq!(count::<Fallback>(&mut app), 1);
+        assert_eq!(count_errors(&app), 1);
+
+        let entities = app.world().iter_entities().count();
+        for number in [3, 5, 7] {
+            app.world_mut().resource_mut::<Number>().0 = number;
+            app.update();
+        }
+        assert_eq!(
+            app.world().iter_entities().count(),
+            entities,
+            "Entities created before a panic should be despawned"
+        );
+
+        app.world_mut().resource_mut::<Number>().0 = 2;
+        app.update();
+
+        assert_eq!(count::<Even>(&mut app), 1);
+        assert_eq!(count::<Leaf>(&mut app), 1);
+        assert_eq!(count::<Fallback>(&mut app), 0);
+    }
+
+    #[test]
+    pub fn test_panic_in_held_child() {
+        #[derive(Debug, Default, Resource)]
+        struct Explode(bool);
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = Res<'w, Explode>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                commands.add_child(0, ErrorBoundary::new(Parent(context.0), Parent(false)), &());
+            }
+        }
+
+        /// Has a child which panics if `self.0` is true
+        #[derive(Debug, Clone, PartialEq)]
+        struct Parent(bool);
+
+        impl MavericNode for Parent {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+                let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+                    return;
+                };
+                commands.add_child(0, Bomb(node.0), &());
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Bomb(bool);
+
+        impl MavericNode for Bomb {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+                let Some((node, _commands)) = commands.unordered_children_with_node() else {
+                    return;
+                };
+                assert!(!node.0, "Boom");
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        // Children are added inside a guard when they are held back
+        app.insert_resource(DuplicateKeyPolicy::KeepLast);
+        app.init_resource::<Explode>();
+        app.register_maveric::<Root>();
+        app.update();
+
+        app.world_mut().resource_mut::<Explode>().0 = true;
+        app.update();
+
+        let events = app.world().resource::<Events<MavericError>>();
+        assert_eq!(events.get_reader().read(events).count(), 1);
+        assert!(
+            GUARDED_ENTITIES.with_borrow(Vec::is_empty),
+            "Every guard should be popped after a panic"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{has_changed::HasChanged, prelude::*};
+use bevy::ecs::{
+    component::Tick,
+    event::ManualEventReader,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+
+/// Events which were sent since the system last ran.
+/// This is marked as changed whenever there are any such events.
+///
+/// `EventReader<E>` can't be used as a context directly: contexts are only borrowed immutably,
+/// so the reader would never advance and would report the same events on every run
+pub struct EventContext<'w, E: Event> {
+    events: Res<'w, Events<E>>,
+    start: usize,
+    end: usize,
+}
+
+impl<E: Event> EventContext<'_, E> {
+    /// Iterate the events which were sent since the system last ran
+    pub fn iter(&self) -> impl Iterator<Item = &E> + '_ {
+        (self.start..self.end).filter_map(|id| self.events.get_event(id).map(|x| x.0))
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl<E: Event> HasChanged for EventContext<'_, E> {
+    fn has_changed(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<E: Event + std::fmt::Debug> std::fmt::Debug for EventContext<'_, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+unsafe impl<E: Event> ReadOnlySystemParam for EventContext<'_, E> {}
+
+pub struct EventContextState<E: Event> {
+    events_state: <Res<'static, Events<E>> as SystemParam>::State,
+    reader: ManualEventReader<E>,
+}
+
+unsafe impl<E: Event> SystemParam for EventContext<'_, E> {
+    type State = EventContextState<E>;
+    type Item<'world, 'state> = EventContext<'world, E>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        EventContextState {
+            events_state: <Res<'static, Events<E>> as SystemParam>::init_state(world, system_meta),
+            reader: ManualEventReader::default(),
+        }
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        let events = <Res<'static, Events<E>> as SystemParam>::get_param(
+            &mut state.events_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+
+        let unread = state.reader.len(&events);
+        state.reader.clear(&events);
+
+        let end = events.oldest_id() + events.len();
+
+        EventContext {
+            events,
+            start: end - unread,
+            end,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    pub fn test_event_context() {
+        #[derive(Debug, Clone, Event)]
+        struct Message(&'static str);
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = EventContext<'w, Message>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                for message in context.iter() {
+                    commands.add_child(message.0, MessageNode(message.0), &());
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct MessageNode(&'static str);
+
+        impl MavericNode for MessageNode {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+            }
+        }
+
+        fn messages(app: &mut App) -> Vec<&'static str> {
+            let mut messages: Vec<_> = app
+                .world_mut()
+                .query::<&MavericNodeComponent<MessageNode>>()
+                .iter(app.world())
+                .map(|x| x.node.0)
+                .collect();
+            messages.sort_unstable();
+            messages
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.add_event::<Message>();
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(messages(&mut app), Vec::<&str>::new());
+
+        app.world_mut().send_event(Message("hello"));
+        app.world_mut().send_event(Message("world"));
+        app.update();
+        assert_eq!(messages(&mut app), vec!["hello", "world"]);
+
+        // The events have already been read so nothing changes
+        app.update();
+        assert_eq!(messages(&mut app), vec!["hello", "world"]);
+
+        app.world_mut().send_event(Message("goodbye"));
+        app.update();
+        assert_eq!(messages(&mut app), vec!["goodbye"]);
+    }
+}
@@ -6,6 +6,15 @@ use bevy::ecs::{
 
 pub trait HasChanged {
     fn has_changed(&self) -> bool;
+
+    /// Push a description of every part of this value which has changed onto `changes`.
+    /// `path` describes where this value is within its parent.
+    /// This is only used for debugging why a root or node was updated.
+    fn changed_paths(&self, path: &str, changes: &mut Vec<String>) {
+        if self.has_changed() {
+            changes.push(format!("{path}: {}", std::any::type_name::<Self>()));
+        }
+    }
 }
 
 impl HasChanged for () {
@@ -20,6 +29,14 @@ impl<'a, T: Resource> HasChanged for Res<'a, T> {
     }
 }
 
+/// Changed when the resource is inserted or changed.
+/// Removing the resource is not a change - use `OptionalRes` to detect that
+impl<T: Resource> HasChanged for Option<Res<'_, T>> {
+    fn has_changed(&self) -> bool {
+        self.as_ref().is_some_and(DetectChanges::is_changed)
+    }
+}
+
 impl<'a, T: Resource> HasChanged for ResMut<'a, T> {
     fn has_changed(&self) -> bool {
         self.is_changed()
@@ -64,6 +81,16 @@ macro_rules! impl_has_changed_tuples {
                 let ($($t,)*) = self;
                 $($T::has_changed($t) ||)* false
             }
+
+            #[allow(clippy::many_single_char_names, unused_assignments)]
+            fn changed_paths(&self, path: &str, changes: &mut Vec<String>) {
+                let ($($t,)*) = self;
+                let mut index = 0usize;
+                $(
+                    $T::changed_paths($t, &format!("{path}.{index}"), changes);
+                    index += 1;
+                )*
+            }
         }
 
 
@@ -82,3 +109,68 @@ impl_has_changed_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i);
 impl_has_changed_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j);
 impl_has_changed_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k);
 impl_has_changed_tuples!(A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l);
+
+#[cfg(test)]
+mod tests {
+    use crate as maveric;
+    use crate::has_changed::HasChanged;
+    use maveric_macro::HasChanged;
+
+    #[derive(Debug, Clone, Copy)]
+    struct Flag(bool);
+
+    impl HasChanged for Flag {
+        fn has_changed(&self) -> bool {
+            self.0
+        }
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    const fn is_positive(value: &i32) -> bool {
+        *value > 0
+    }
+
+    #[derive(HasChanged)]
+    struct Named<'a> {
+        flag: Flag,
+        #[has_changed(skip)]
+        _label: &'a str,
+        #[has_changed(with = is_positive)]
+        delta: i32,
+    }
+
+    #[derive(HasChanged)]
+    struct Tuple(Flag, #[has_changed(skip)] Flag);
+
+    #[derive(HasChanged)]
+    struct Unit;
+
+    fn changed_paths(value: &impl HasChanged) -> Vec<String> {
+        let mut changes = vec![];
+        value.changed_paths("root", &mut changes);
+        changes
+    }
+
+    #[test]
+    pub fn test_derive_struct() {
+        let named = |flag, delta| Named {
+            flag: Flag(flag),
+            _label: "label",
+            delta,
+        };
+        assert!(!named(false, 0).has_changed());
+        assert!(named(true, 0).has_changed());
+        assert!(named(false, 1).has_changed());
+        assert_eq!(changed_paths(&named(false, 1)), vec!["root.delta"]);
+
+        assert!(Tuple(Flag(true), Flag(false)).has_changed());
+        let tuple = Tuple(Flag(false), Flag(true));
+        assert!(
+            !tuple.has_changed() && tuple.1.has_changed(),
+            "Skipped fields are ignored"
+        );
+
+        assert!(!Unit.has_changed());
+        assert_eq!(changed_paths(&Unit), Vec::<String>::new());
+    }
+}
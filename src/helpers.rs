@@ -6,12 +6,15 @@ pub(crate) fn create_recursive<'w, 's, R: MavericRoot, N: MavericNode>(
     mut entity_commands: EntityCommands,
     node: N,
     context: &N::Context<'w, 's>,
+    ambient: Ambient,
     key: ChildKey,
     world: &World,
     alloc: &Allocator,
 ) -> Entity {
+    crate::error_boundary::record_created(entity_commands.id());
+
     let component_commands = SetComponentCommands::<N, N::Context<'w, 's>>::new(
-        NodeArgs::new(context, SetEvent::Created, &node, None),
+        NodeArgs::new(context, SetEvent::Created, &node, None, ambient),
         world,
         &mut entity_commands,
     );
@@ -19,18 +22,29 @@ pub(crate) fn create_recursive<'w, 's, R: MavericRoot, N: MavericNode>(
     N::set_components(component_commands);
 
     let children_commands = SetChildrenCommands::<N, N::Context<'w, 's>, R>::new(
-        NodeArgs::new(context, SetEvent::Created, &node, None),
+        NodeArgs::new(context, SetEvent::Created, &node, None, ambient),
         world,
         &mut entity_commands,
         alloc,
     );
 
     N::set_children(children_commands);
+
+    #[cfg(feature = "tracing")]
+    {
+        crate::tracing::record_node_change::<N>(
+            entity_commands.id(),
+            SetEvent::Created,
+            true,
+            context,
+        );
+    }
+
     node.on_created(context, world, &mut entity_commands);
     let node_component = MavericNodeComponent::new(node);
     let child_component = MavericChildComponent::<R>::new::<N>(key);
 
-    entity_commands.insert((node_component, child_component));
+    entity_commands.try_insert((node_component, child_component));
 
     entity_commands.id()
 }
@@ -46,7 +60,7 @@ pub(crate) fn delete_recursive<R: MavericRoot>(
         return Some(entity);
     }
 
-    let mut ec = commands.entity(entity);
+    let mut ec = commands.get_entity(entity)?;
 
     let mut cc = ComponentCommands::new(&mut ec, world, SetEvent::Updated);
 
@@ -58,7 +72,7 @@ pub(crate) fn delete_recursive<R: MavericRoot>(
 
     match dp {
         DeletionPolicy::DeleteImmediately => {
-            ec.despawn_recursive();
+            despawn_managed(&mut ec.commands(), entity);
             None
         }
         DeletionPolicy::Linger(duration) => {
@@ -71,15 +85,45 @@ pub(crate) fn delete_recursive<R: MavericRoot>(
     }
 }
 
+/// Update an entity whose node has not changed but which must be updated anyway
+pub(crate) fn refresh_recursive<'w, 's, R: MavericRoot, N: MavericNode>(
+    commands: &mut Commands,
+    entity: Entity,
+    node: &N,
+    context: &N::Context<'w, 's>,
+    ambient: Ambient,
+    world: &World,
+    alloc: &Allocator,
+) {
+    let Some(mut ec) = commands.get_entity(entity) else {
+        return;
+    };
+
+    let mut args = NodeArgs::new(context, SetEvent::Updated, node, Some(node), ambient);
+    args.hot = true;
+
+    N::set_components(SetComponentCommands::<N, N::Context<'w, 's>>::new(
+        args.clone(),
+        world,
+        &mut ec,
+    ));
+    N::set_children(SetChildrenCommands::<N, N::Context<'w, 's>, R>::new(
+        args, world, &mut ec, alloc,
+    ));
+}
+
 pub(crate) fn update_recursive<'w, 's, R: MavericRoot, N: MavericNode>(
     commands: &mut Commands,
     entity: Entity,
     node: N,
     context: &N::Context<'w, 's>,
+    ambient: Ambient,
     world: &World,
     alloc: &Allocator,
 ) {
-    let mut ec = commands.entity(entity);
+    let Some(mut ec) = commands.get_entity(entity) else {
+        return;
+    };
     let undeleted = if world.get::<ScheduledForDeletion>(entity).is_some() {
         ec.remove::<ScheduledForDeletion>();
         //info!("Node Undeleted");
@@ -98,29 +142,37 @@ pub(crate) fn update_recursive<'w, 's, R: MavericRoot, N: MavericNode>(
     };
 
     let component_commands = SetComponentCommands::<N, N::Context<'w, 's>>::new(
-        NodeArgs::new(context, event, &node, previous),
+        NodeArgs::new(context, event, &node, previous, ambient),
         world,
         &mut ec,
     );
 
     N::set_components(component_commands);
 
-    let children_commands = SetChildrenCommands::<N, N::Context<'w, 's>, R>::new(
-        NodeArgs::new(context, event, &node, previous),
-        world,
-        &mut ec,
-        alloc,
-    );
+    let mut children_args = NodeArgs::new(context, event, &node, previous, ambient);
+    // A child was removed from outside maveric so the children must be reconciled to add it again
+    if world.get::<RepairChildren>(entity).is_some() {
+        ec.remove::<RepairChildren>();
+        children_args.hot = true;
+    }
+
+    let children_commands =
+        SetChildrenCommands::<N, N::Context<'w, 's>, R>::new(children_args, world, &mut ec, alloc);
     N::set_children(children_commands);
 
     let node_changed = previous.map_or(true, |p| !p.eq(&node));
 
+    #[cfg(feature = "tracing")]
+    {
+        crate::tracing::record_node_change::<N>(entity, event, node_changed, context);
+    }
+
     if node_changed {
         if let Some(previous) = previous {
             node.on_changed(previous, context, world, &mut ec);
         } else {
             node.on_created(context, world, &mut ec);
         }
-        ec.insert(MavericNodeComponent::<N> { node });
+        ec.try_insert(MavericNodeComponent::<N> { node });
     }
 }
@@ -4,6 +4,10 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::option_if_let_else)]
 
+pub mod ambient;
+#[cfg(feature = "bevy_asset")]
+pub mod assets_context;
+pub mod async_memo;
 pub mod cached;
 pub mod child_commands;
 pub mod child_deletion_policy;
@@ -12,23 +16,33 @@ pub mod child_tuple;
 pub mod component_commands;
 pub mod components;
 pub mod deleter;
+pub mod duplicate_key_policy;
+pub mod error_boundary;
+pub mod event_context;
 pub mod has_changed;
 pub mod helpers;
 pub mod into_components;
+pub mod local_state;
+pub mod managed_entities;
 pub mod node;
 pub mod with_previous;
 
 pub mod maveric_context;
+pub mod maveric_error;
 pub mod node_args;
+pub mod optional_res;
 pub mod plugin;
+pub mod query_context;
 pub mod root;
 pub mod root_commands;
 pub mod scheduled_change;
+pub mod select;
 pub mod scheduled_for_deletion;
 pub mod set_children_commands;
 pub mod set_components_commands;
 pub mod set_event;
 pub mod transition;
+pub mod untracked;
 pub mod memo;
 
 #[cfg(feature = "tracing")]
@@ -38,6 +52,10 @@ pub mod widgets;
 pub mod with_bundle;
 
 pub mod prelude {
+    pub use crate::ambient::*;
+    #[cfg(feature = "bevy_asset")]
+    pub use crate::assets_context::*;
+    pub use crate::async_memo::*;
     pub use crate::child_commands::*;
     pub use crate::child_deletion_policy::*;
     pub use crate::child_key::*;
@@ -45,30 +63,41 @@ pub mod prelude {
     pub use crate::component_commands::*;
 
     pub use crate::deleter::*;
+    pub use crate::duplicate_key_policy::{DuplicateKeyEvent, DuplicateKeyPolicy};
+    pub use crate::error_boundary::*;
+    pub use crate::event_context::*;
     pub use crate::into_components::*;
+    pub use crate::local_state::*;
     pub use crate::maveric_context::*;
+    pub use crate::maveric_error::*;
     pub use crate::node::*;
     pub use crate::node_args::*;
+    pub use crate::optional_res::*;
     pub use crate::plugin::*;
+    pub use crate::query_context::*;
     pub use crate::root::*;
     pub use crate::scheduled_change::*;
     pub use crate::scheduled_for_deletion::*;
+    pub use crate::select::*;
     pub use crate::set_children_commands::*;
     pub use crate::set_components_commands::*;
     pub use crate::set_event::*;
     pub use crate::transition::prelude::*;
+    pub use crate::untracked::*;
     pub use crate::with_bundle;
 
     #[cfg(any(feature = "widgets", test))]
     pub use crate::widgets::prelude::*;
 
     pub(crate) use crate::components::*;
+    pub(crate) use crate::duplicate_key_policy::*;
 
     pub(crate) use crate::helpers::*;
+    pub(crate) use crate::managed_entities::*;
     pub(crate) use crate::root_commands::*;
 
     #[cfg(any(feature = "derive", test))]
-    pub use maveric_macro::HasChanged;
+    pub use maveric_macro::{HasChanged, MavericContext};
 
     #[cfg(feature = "bumpalo")]
     pub(crate) type Allocator = bumpalo::Bump;
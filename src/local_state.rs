@@ -0,0 +1,400 @@
+use crate::prelude::*;
+use bevy::ecs::system::{EntityCommands, StaticSystemParam, SystemParam};
+use std::{borrow::BorrowMut, marker::PhantomData, ops::Deref};
+
+/// A node with view-only state stored in a component on its entity.
+///
+/// The state is initialised with its default value when the node is created.
+/// Read it with `local_state` on the node's commands and write it from any system.
+pub trait StatefulNode: MavericNode {
+    type State: Component + Default;
+}
+
+pub trait CanRegisterLocalState {
+    /// Initialise the local state of nodes of type `N` under root `R` when they are created and update them whenever it changes.
+    ///
+    /// Only the node whose state changed and its descendants are visited, not the whole root
+    fn register_local_state<R: MavericRoot, N: StatefulNode>(&mut self) -> &mut Self
+    where
+        for<'w, 's, 'w2, 's2> N::Context<'w, 's>:
+            SystemParam<Item<'w2, 's2> = N::Context<'w2, 's2>>;
+}
+
+impl CanRegisterLocalState for App {
+    fn register_local_state<R: MavericRoot, N: StatefulNode>(&mut self) -> &mut Self
+    where
+        for<'w, 's, 'w2, 's2> N::Context<'w, 's>:
+            SystemParam<Item<'w2, 's2> = N::Context<'w2, 's2>>,
+    {
+        if !self.is_plugin_added::<InitLocalStatePlugin<N>>() {
+            self.add_plugins(InitLocalStatePlugin::<N>(PhantomData));
+        }
+        self.add_systems(First, sync_local_state::<R, N>.after(sync_state::<R>));
+        self
+    }
+}
+
+/// Observes the creation of nodes of type `N`. This is shared by every root which registers `N`
+struct InitLocalStatePlugin<N>(PhantomData<fn() -> N>);
+
+impl<N: StatefulNode> Plugin for InitLocalStatePlugin<N> {
+    fn build(&self, app: &mut App) {
+        app.observe(init_local_state::<N>);
+    }
+}
+
+/// The local state of a node
+#[derive(Debug)]
+pub enum LocalState<'w, S> {
+    /// The state stored on the entity
+    Stored(&'w S),
+    /// The default state, which will be stored on the entity
+    Initial(S),
+}
+
+impl<'w, S: Component + Default> LocalState<'w, S> {
+    pub(crate) fn get_or_init(world: &'w World, ec: &mut EntityCommands) -> Self {
+        if let Some(state) = world.get::<S>(ec.id()) {
+            LocalState::Stored(state)
+        } else {
+            ec.insert(S::default());
+            LocalState::Initial(S::default())
+        }
+    }
+}
+
+impl<S> Deref for LocalState<'_, S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            LocalState::Stored(state) => state,
+            LocalState::Initial(state) => state,
+        }
+    }
+}
+
+/// Insert the default state for nodes which did not read it while being created
+#[allow(clippy::needless_pass_by_value)]
+fn init_local_state<N: StatefulNode>(
+    trigger: Trigger<OnAdd, MavericNodeComponent<N>>,
+    mut commands: Commands,
+    states: Query<(), With<N::State>>,
+) {
+    let entity = trigger.entity();
+    if !states.contains(entity) {
+        commands.entity(entity).insert(N::State::default());
+    }
+}
+
+type ChangedStateQuery<'w, 's, R, N> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static MavericNodeComponent<N>,
+        Ref<'static, <N as StatefulNode>::State>,
+    ),
+    (
+        Changed<<N as StatefulNode>::State>,
+        With<MavericChildComponent<R>>,
+        Without<ScheduledForDeletion>,
+    ),
+>;
+
+#[allow(clippy::needless_pass_by_value)]
+fn sync_local_state<'w, 's, R: MavericRoot, N: StatefulNode>(
+    mut commands: Commands,
+    param: StaticSystemParam<N::Context<'w, 's>>,
+    query: ChangedStateQuery<R, N>,
+    world: &World,
+    mut allocator: Local<Allocator>,
+) where
+    for<'w1, 's1, 'w2, 's2> N::Context<'w1, 's1>:
+        SystemParam<Item<'w2, 's2> = N::Context<'w2, 's2>>,
+{
+    let inner = param.into_inner();
+    let allocator = allocator.borrow_mut();
+
+    for (entity, node_component, state) in &query {
+        // The state was inserted when the node was created so the node is already up to date
+        if state.is_added() {
+            continue;
+        }
+        Ambient::with_ancestors(world, entity, |ambient| {
+            refresh_recursive::<R, N>(
+                &mut commands,
+                entity,
+                &node_component.node,
+                &inner,
+                ambient,
+                world,
+                allocator,
+            );
+        });
+    }
+
+    reset_allocator(allocator);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default, Resource)]
+    struct Title(&'static str);
+
+    static ROOT_UPDATES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Title>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            ROOT_UPDATES.fetch_add(1, Ordering::SeqCst);
+            commands.add_child(0, Accordion(context.0), &());
+        }
+    }
+
+    #[derive(Debug, Default, Component)]
+    struct Expanded(bool);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Accordion(&'static str);
+
+    impl StatefulNode for Accordion {
+        type State = Expanded;
+    }
+
+    impl MavericNode for Accordion {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            mut commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+            let expanded = commands.local_state().0;
+            let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+                return;
+            };
+            if expanded {
+                commands.add_child(0, Content(node.0), &());
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Content(&'static str);
+
+    impl MavericNode for Content {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn content(app: &mut App) -> Option<&'static str> {
+        app.world_mut()
+            .query::<&MavericNodeComponent<Content>>()
+            .iter(app.world())
+            .map(|x| x.node.0)
+            .next()
+    }
+
+    fn set_expanded(app: &mut App, expanded: bool) {
+        let world = app.world_mut();
+        let mut state = world.query::<&mut Expanded>().single_mut(world);
+        state.0 = expanded;
+    }
+
+    #[test]
+    pub fn test_local_state() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.insert_resource(Title("hello"));
+        app.register_maveric::<Root>();
+        app.register_local_state::<Root, Accordion>();
+
+        app.update();
+        app.update();
+        assert_eq!(content(&mut app), None);
+        assert_eq!(ROOT_UPDATES.load(Ordering::SeqCst), 1);
+
+        set_expanded(&mut app, true);
+        app.update();
+        assert_eq!(content(&mut app), Some("hello"));
+        assert_eq!(
+            ROOT_UPDATES.load(Ordering::SeqCst),
+            1,
+            "Changing local state should not update the root"
+        );
+
+        app.world_mut().resource_mut::<Title>().0 = "goodbye";
+        app.update();
+        assert_eq!(content(&mut app), Some("goodbye"));
+        assert_eq!(ROOT_UPDATES.load(Ordering::SeqCst), 2);
+
+        set_expanded(&mut app, false);
+        app.update();
+        assert_eq!(content(&mut app), None);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Theme(&'static str);
+
+    #[derive(Debug)]
+    struct ThemedRoot;
+
+    impl MavericRoot for ThemedRoot {
+        type Context<'w, 's> = Res<'w, Title>;
+
+        fn set_children(_context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(0, Provider::new(Theme("dark"), ThemedAccordion), &());
+            commands.add_child(1, Unread, &());
+        }
+    }
+
+    /// Shows the theme when expanded
+    #[derive(Debug, Clone, PartialEq)]
+    struct ThemedAccordion;
+
+    impl StatefulNode for ThemedAccordion {
+        type State = Expanded;
+    }
+
+    impl MavericNode for ThemedAccordion {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            mut commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+            let expanded = commands.local_state().0;
+            let theme = commands.ambient::<Theme>().map_or("none", |x| x.0);
+            let Some(mut commands) = commands.ignore_node().unordered_children() else {
+                return;
+            };
+            if expanded {
+                commands.add_child(0, Content(theme), &());
+            }
+        }
+    }
+
+    /// A node which never reads its state
+    #[derive(Debug, Clone, PartialEq)]
+    struct Unread;
+
+    impl StatefulNode for Unread {
+        type State = Expanded;
+    }
+
+    impl MavericNode for Unread {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    #[test]
+    pub fn test_local_state_under_provider() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.init_resource::<Title>();
+        app.register_maveric::<ThemedRoot>();
+        app.register_local_state::<ThemedRoot, ThemedAccordion>();
+        app.register_local_state::<ThemedRoot, Unread>();
+
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(
+            world.query::<&Expanded>().iter(world).count(),
+            2,
+            "State should be added when the node is created even if it is not read"
+        );
+
+        let world = app.world_mut();
+        let entity = world
+            .query_filtered::<Entity, With<MavericNodeComponent<ThemedAccordion>>>()
+            .single(world);
+        world.get_mut::<Expanded>(entity).unwrap().0 = true;
+        app.update();
+        assert_eq!(
+            content(&mut app),
+            Some("dark"),
+            "Values provided above a node should be available when its state changes"
+        );
+    }
+
+    #[derive(Debug)]
+    struct LeftRoot;
+
+    impl MavericRoot for LeftRoot {
+        type Context<'w, 's> = Res<'w, Title>;
+
+        fn set_children(_context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(0, Accordion("left"), &());
+        }
+    }
+
+    #[derive(Debug)]
+    struct RightRoot;
+
+    impl MavericRoot for RightRoot {
+        type Context<'w, 's> = Res<'w, Title>;
+
+        fn set_children(_context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(0, Accordion("right"), &());
+        }
+    }
+
+    #[test]
+    pub fn test_local_state_under_two_roots() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.init_resource::<Title>();
+        app.register_maveric::<LeftRoot>();
+        app.register_maveric::<RightRoot>();
+        app.register_local_state::<LeftRoot, Accordion>();
+        app.register_local_state::<RightRoot, Accordion>();
+        app.update();
+
+        let world = app.world_mut();
+        let left = world
+            .query_filtered::<Entity, (
+                With<MavericNodeComponent<Accordion>>,
+                With<MavericChildComponent<LeftRoot>>,
+            )>()
+            .single(world);
+        world.get_mut::<Expanded>(left).unwrap().0 = true;
+        app.update();
+
+        let world = app.world_mut();
+        let contents: Vec<_> = world
+            .query::<(
+                &MavericNodeComponent<Content>,
+                Has<MavericChildComponent<LeftRoot>>,
+            )>()
+            .iter(world)
+            .map(|(content, is_left)| (content.node.0, is_left))
+            .collect();
+        assert_eq!(
+            contents,
+            vec![("left", true)],
+            "Only the root which owns the node should refresh it"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use std::marker::PhantomData;
+
+use crate::prelude::*;
+use bevy::{
+    ecs::{component::ComponentId, world::DeferredWorld},
+    utils::HashSet,
+};
+
+/// Set while maveric is despawning its own entities so that those despawns are not reported
+#[derive(Debug, Default, Resource)]
+pub(crate) struct DespawningManaged(bool);
+
+/// Managed entities which were despawned or lost their `MavericChildComponent` outside of maveric
+#[derive(Resource)]
+pub(crate) struct ManagedEntityRepairs<R: MavericRoot> {
+    damaged: Vec<DamagedEntity>,
+    /// Whether the root must be synced even though its context has not changed
+    pub(crate) resync: bool,
+    phantom: PhantomData<R>,
+}
+
+impl<R: MavericRoot> Default for ManagedEntityRepairs<R> {
+    fn default() -> Self {
+        Self {
+            damaged: vec![],
+            resync: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DamagedEntity {
+    entity: Entity,
+    parent: Option<Entity>,
+}
+
+/// Marks a managed entity whose children must be reconciled on the next sync because one of them was removed.
+/// Its managed ancestors are also marked so that the sync reaches it
+#[derive(Debug, Default, Component)]
+pub(crate) struct RepairChildren;
+
+/// Watch for managed entities being removed from outside maveric.
+/// When this happens the parent of the entity adds it again and a `MavericError` is sent
+pub(crate) fn register_managed_entity_checks<R: MavericRoot>(app: &mut App) {
+    if app.world().contains_resource::<ManagedEntityRepairs<R>>() {
+        return;
+    }
+
+    app.init_resource::<DespawningManaged>()
+        .init_resource::<ManagedEntityRepairs<R>>();
+
+    app.world_mut()
+        .register_component_hooks::<MavericChildComponent<R>>()
+        .on_remove(on_child_component_removed::<R>);
+
+    app.add_systems(
+        First,
+        repair_managed_entities::<R>
+            .run_if(|repairs: Res<ManagedEntityRepairs<R>>| !repairs.damaged.is_empty()),
+    );
+}
+
+/// Despawn a managed entity and its descendants without reporting it.
+/// Does nothing if the entity no longer exists
+pub(crate) fn despawn_managed(commands: &mut Commands, entity: Entity) {
+    commands.add(move |world: &mut World| despawn_managed_now(world, entity));
+}
+
+fn despawn_managed_now(world: &mut World, entity: Entity) {
+    if let Some(mut despawning) = world.get_resource_mut::<DespawningManaged>() {
+        despawning.0 = true;
+    }
+
+    if let Some(entity_mut) = world.get_entity_mut(entity) {
+        entity_mut.despawn_recursive();
+    }
+
+    if let Some(mut despawning) = world.get_resource_mut::<DespawningManaged>() {
+        despawning.0 = false;
+    }
+}
+
+fn on_child_component_removed<R: MavericRoot>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _component_id: ComponentId,
+) {
+    let Some(DespawningManaged(false)) = world.get_resource::<DespawningManaged>() else {
+        return;
+    };
+
+    let parent = world.get::<Parent>(entity).map(Parent::get);
+
+    if let Some(mut repairs) = world.get_resource_mut::<ManagedEntityRepairs<R>>() {
+        repairs.damaged.push(DamagedEntity { entity, parent });
+    }
+}
+
+pub(crate) fn repair_managed_entities<R: MavericRoot>(world: &mut World) {
+    let damaged = std::mem::take(&mut world.resource_mut::<ManagedEntityRepairs<R>>().damaged);
+
+    let damaged_entities: HashSet<Entity> = damaged.iter().map(|x| x.entity).collect();
+
+    for damaged in damaged {
+        // Descendants of a removed entity are added again along with it
+        if damaged
+            .parent
+            .is_some_and(|parent| damaged_entities.contains(&parent))
+        {
+            continue;
+        }
+
+        let error = MavericError {
+            entity: damaged.entity,
+            kind: MavericErrorKind::ExternallyRemoved,
+        };
+
+        // The entity was removed along with an ancestor which maveric does not manage
+        if damaged
+            .parent
+            .is_some_and(|parent| world.get_entity(parent).is_none())
+        {
+            debug!("{error}");
+        } else {
+            warn!("{error}");
+            world.send_event(error);
+        }
+
+        // Remove the entity if it is still a child so that its parent creates it again
+        despawn_managed_now(world, damaged.entity);
+
+        let mut current = damaged.parent;
+        while let Some(entity) = current {
+            if world.get::<MavericChildComponent<R>>(entity).is_none() {
+                break;
+            }
+            world.entity_mut(entity).insert(RepairChildren);
+            current = world.get::<Parent>(entity).map(Parent::get);
+        }
+    }
+
+    world.resource_mut::<ManagedEntityRepairs<R>>().resync = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{ecs::event::ManualEventReader, time::TimePlugin};
+
+    #[test]
+    pub fn test_externally_removed() {
+        #[derive(Debug, Clone, PartialEq, Eq, Resource, Default)]
+        struct LeafCount(u32);
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = Res<'w, LeafCount>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                commands.add_child(0, Branch(context.0), &());
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Branch(u32);
+
+        impl MavericNode for Branch {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+                let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+                    return;
+                };
+                for x in 0..node.0 {
+                    commands.add_child(x, Leaf, &());
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Leaf;
+
+        impl MavericNode for Leaf {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+            }
+        }
+
+        fn leaves(app: &mut App) -> Vec<Entity> {
+            app.world_mut()
+                .query_filtered::<Entity, With<MavericNodeComponent<Leaf>>>()
+                .iter(app.world())
+                .collect()
+        }
+
+        fn get_branch(app: &mut App) -> Entity {
+            app.world_mut()
+                .query_filtered::<Entity, With<MavericNodeComponent<Branch>>>()
+                .single(app.world())
+        }
+
+        fn count_errors(app: &App, reader: &mut ManualEventReader<MavericError>) -> usize {
+            let events = app.world().resource::<Events<MavericError>>();
+            reader
+                .read(events)
+                .filter(|x| x.kind == MavericErrorKind::ExternallyRemoved)
+                .count()
+        }
+
+        let mut reader = ManualEventReader::default();
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.insert_resource(LeafCount(3));
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(leaves(&mut app).len(), 3);
+
+        let branch = get_branch(&mut app);
+
+        // Despawn a leaf
+        let mut remaining = leaves(&mut app);
+        let leaf = remaining.remove(0);
+        app.world_mut().entity_mut(leaf).despawn_recursive();
+        app.update();
+
+        assert_eq!(count_errors(&app, &mut reader), 1);
+        assert_eq!(leaves(&mut app).len(), 3);
+        // Only the missing leaf is added again
+        assert_eq!(get_branch(&mut app), branch);
+        assert!(remaining.iter().all(|x| leaves(&mut app).contains(x)));
+
+        // Remove the bookkeeping component from a leaf
+        let mut remaining = leaves(&mut app);
+        let leaf = remaining.remove(0);
+        app.world_mut()
+            .entity_mut(leaf)
+            .remove::<MavericChildComponent<Root>>();
+        app.update();
+
+        assert_eq!(count_errors(&app, &mut reader), 1);
+        assert!(app.world().get_entity(leaf).is_none());
+        assert_eq!(leaves(&mut app).len(), 3);
+        assert_eq!(get_branch(&mut app), branch);
+        assert!(remaining.iter().all(|x| leaves(&mut app).contains(x)));
+
+        // Despawning a parent is only reported once
+        app.world_mut().entity_mut(branch).despawn_recursive();
+        app.update();
+
+        assert_eq!(count_errors(&app, &mut reader), 1);
+        assert_eq!(leaves(&mut app).len(), 3);
+
+        // Ordinary updates are not reported
+        app.insert_resource(LeafCount(1));
+        app.update();
+
+        assert_eq!(count_errors(&app, &mut reader), 0);
+        assert_eq!(leaves(&mut app).len(), 1);
+    }
+}
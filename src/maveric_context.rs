@@ -2,8 +2,27 @@ use bevy::ecs::system::{ReadOnlySystemParam, SystemParam};
 
 use crate::has_changed::HasChanged;
 
+/// Implemented for every `ReadOnlySystemParam`.
+/// Used by `#[derive(MavericContext)]` to report fields which could mutate the world
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used in a `MavericContext` because it is not read-only",
+    label = "this parameter could mutate the world",
+    note = "contexts are only read when updating nodes. Use `Res` rather than `ResMut` and `&T` rather than `&mut T` in queries"
+)]
+pub trait ReadOnlyContextParam: ReadOnlySystemParam {}
+
+impl<T: ReadOnlySystemParam> ReadOnlyContextParam for T {}
+
 pub trait MavericContext: ReadOnlySystemParam + HasChanged {
     fn has_item_changed(item: &<Self as SystemParam>::Item<'_, '_>) -> bool;
+
+    /// Describe which parts of the item have changed.
+    /// This is only used for debugging why a root was updated.
+    fn item_changed_paths(
+        item: &<Self as SystemParam>::Item<'_, '_>,
+        path: &str,
+        changes: &mut Vec<String>,
+    );
 }
 
 impl<R: ReadOnlySystemParam + HasChanged> MavericContext for R
@@ -21,6 +40,94 @@ where
             ti.has_changed()
         }
     }
+
+    fn item_changed_paths<'a, 'w, 's>(
+        item: &'a <Self as SystemParam>::Item<'w, 's>,
+        path: &str,
+        changes: &mut Vec<String>,
+    ) {
+        unsafe {
+            let ti = std::mem::transmute::<
+                &'a <Self as SystemParam>::Item<'w, 's>,
+                &'static <Self as SystemParam>::Item<'static, 'static>,
+            >(item);
+
+            ti.changed_paths(path, changes);
+        }
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate as maveric;
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    pub fn test_derive_maveric_context() {
+        #[derive(Debug, Default, Resource)]
+        struct Score(u32);
+
+        #[derive(Debug, Default, Resource)]
+        struct Frame(u32);
+
+        #[derive(MavericContext)]
+        struct ScoreContext<'w> {
+            score: Res<'w, Score>,
+            #[maveric(ignore_changes)]
+            frame: Res<'w, Frame>,
+        }
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = ScoreContext<'w>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                commands.add_child(0, ScoreNode(context.score.0, context.frame.0), &());
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct ScoreNode(u32, u32);
+
+        impl MavericNode for ScoreNode {
+            type Context<'w, 's> = ();
 
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+            }
+        }
+
+        fn score_node(app: &mut App) -> (u32, u32) {
+            let node = app
+                .world_mut()
+                .query::<&MavericNodeComponent<ScoreNode>>()
+                .single(app.world())
+                .node
+                .clone();
+            (node.0, node.1)
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.init_resource::<Score>();
+        app.init_resource::<Frame>();
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(score_node(&mut app), (0, 0));
+
+        // Changes to ignored fields do not cause an update
+        app.world_mut().resource_mut::<Frame>().0 = 1;
+        app.update();
+        assert_eq!(score_node(&mut app), (0, 0));
+
+        app.world_mut().resource_mut::<Score>().0 = 2;
+        app.update();
+        assert_eq!(score_node(&mut app), (2, 1));
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+/// Sent when a node fails in a way it can recover from.
+/// Use `ComponentCommands::report_error` to send these
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct MavericError {
+    /// The entity of the node which reported the error
+    pub entity: Entity,
+    pub kind: MavericErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MavericErrorKind {
+    /// A resource required by the node was missing. Contains the resource type name
+    MissingResource(&'static str),
+    /// A node panicked while inside an `ErrorBoundary`. Contains the panic message
+    Panic(String),
+    /// The entity was despawned or lost its maveric components outside of maveric.
+    /// The top level subtree containing it has been rebuilt
+    ExternallyRemoved,
+    /// Any other error
+    Custom(String),
+}
+
+impl std::fmt::Display for MavericError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            MavericErrorKind::MissingResource(resource) => {
+                write!(
+                    f,
+                    "Node {:?} could not get resource {resource}",
+                    self.entity
+                )
+            }
+            MavericErrorKind::Panic(message) => {
+                write!(f, "Node {:?} panicked: {message}", self.entity)
+            }
+            MavericErrorKind::ExternallyRemoved => {
+                write!(f, "Node {:?} was removed outside of maveric", self.entity)
+            }
+            MavericErrorKind::Custom(message) => write!(f, "Node {:?}: {message}", self.entity),
+        }
+    }
+}
+
+impl std::error::Error for MavericError {}
+
+/// Log the error and send it as an event when the commands are applied
+pub(crate) fn send_error(commands: &mut Commands, error: MavericError) {
+    commands.add(move |world: &mut World| {
+        warn!("{error}");
+        world.send_event(error);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    pub fn test_missing_asset_server() {
+        #[derive(Debug, Default, Resource)]
+        struct ShowSprite;
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = Res<'w, ShowSprite>;
+
+            fn set_children(_context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                commands.add_child(
+                    0,
+                    SpriteNode {
+                        texture_path: "missing.png",
+                        sprite: Sprite::default(),
+                    },
+                    &(),
+                );
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.init_resource::<ShowSprite>();
+        app.register_maveric::<Root>();
+        app.update();
+
+        let events = app.world().resource::<Events<MavericError>>();
+        let kinds: Vec<_> = events
+            .get_reader()
+            .read(events)
+            .map(|x| x.kind.clone())
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![MavericErrorKind::MissingResource(std::any::type_name::<
+                AssetServer,
+            >())]
+        );
+
+        let sprites = app
+            .world_mut()
+            .query::<&MavericNodeComponent<SpriteNode>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(sprites, 1, "The sprite node should still be created");
+    }
+}
@@ -5,7 +5,26 @@ use std::ops::Deref;
 /// A value that can be used inside a memo
 pub trait MemoValue: PartialEq + Send + Sync + 'static {
     type Argument<'world, 'state>: SystemParam + ReadOnlySystemParam;
-    fn calculate<'w, 's>(arg: &<Self::Argument<'w, 's> as SystemParam>::Item<'w, 's>) -> Self; //todo take previous value???
+    fn calculate<'w, 's>(arg: &<Self::Argument<'w, 's> as SystemParam>::Item<'w, 's>) -> Self;
+
+    /// Update the previous value when the argument has changed.
+    /// Returns whether the value has changed.
+    /// Override this to update the value incrementally or to avoid a full comparison
+    fn update<'w, 's>(
+        &mut self,
+        arg: &<Self::Argument<'w, 's> as SystemParam>::Item<'w, 's>,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        let new_value = Self::calculate(arg);
+        if &new_value == self {
+            false
+        } else {
+            *self = new_value;
+            true
+        }
+    }
 }
 
 /// A memo contains a value that is derived from one or more other `SystemParam`s
@@ -90,19 +109,8 @@ where
 
         let has_changed: bool;
 
-        if let Some(prev_data) = &state.data {
-            if item.has_changed() {
-                let new_value = <T as MemoValue>::calculate(&item);
-
-                if &new_value == prev_data {
-                    has_changed = false;
-                } else {
-                    state.data = Some(new_value);
-                    has_changed = true;
-                }
-            } else {
-                has_changed = false;
-            }
+        if let Some(prev_data) = &mut state.data {
+            has_changed = item.has_changed() && prev_data.update(&item);
         } else {
             has_changed = true;
             let new_value = <T as MemoValue>::calculate(&item);
@@ -263,6 +271,64 @@ pub mod tests {
 
         assert_times_updated(3);
     }
+    #[test]
+    pub fn test_incremental_update() {
+        /// The sum of every value the counter has had
+        #[derive(PartialEq, Debug)]
+        pub struct CounterTotal(usize);
+
+        static TIMES_CALCULATED: AtomicUsize = AtomicUsize::new(0);
+
+        impl MemoValue for CounterTotal {
+            type Argument<'world, 'state> = Res<'world, Counter>;
+
+            fn calculate<'w, 's>(
+                arg: &<Self::Argument<'w, 's> as bevy::ecs::system::SystemParam>::Item<'w, 's>,
+            ) -> Self {
+                TIMES_CALCULATED.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Self(arg.0)
+            }
+
+            fn update<'w, 's>(
+                &mut self,
+                arg: &<Self::Argument<'w, 's> as bevy::ecs::system::SystemParam>::Item<'w, 's>,
+            ) -> bool {
+                self.0 += arg.0;
+                arg.0 != 0
+            }
+        }
+
+        #[derive(Debug, Resource, Default)]
+        struct Totals(Vec<(usize, bool)>);
+
+        fn record_total(total: Memo<CounterTotal>, mut totals: ResMut<Totals>) {
+            totals.0.push((total.0, total.has_changed()));
+        }
+
+        let mut app = App::new();
+
+        app.init_resource::<Counter>();
+        app.init_resource::<Totals>();
+        app.add_systems(Update, record_total);
+
+        app.update();
+        set_count(&mut app, 2);
+        app.update();
+        app.update();
+        set_count(&mut app, 3);
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Totals>().0,
+            vec![(0, true), (2, true), (2, false), (5, true)]
+        );
+        assert_eq!(
+            TIMES_CALCULATED.load(std::sync::atomic::Ordering::SeqCst),
+            1,
+            "The value should only be fully calculated once"
+        );
+    }
+
     fn set_count(app: &mut App, new_count: usize) {
         let mut r = app.world_mut().resource_mut::<Counter>();
 
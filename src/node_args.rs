@@ -6,6 +6,10 @@ pub struct NodeArgs<'n, 'p, 'c1, N: PartialEq, C: MavericContext> {
     pub event: SetEvent,
     pub node: &'n N,
     pub previous: Option<&'p N>,
+    pub(crate) ambient: Ambient<'c1>,
+    /// Whether this must be updated even if the node and context have not changed,
+    /// e.g. because an ambient value it reads or its local state has changed
+    pub(crate) hot: bool,
 }
 
 impl<'n, 'p, 'c1, N: PartialEq, C: MavericContext> Clone for NodeArgs<'n, 'p, 'c1, N, C> {
@@ -15,6 +19,8 @@ impl<'n, 'p, 'c1, N: PartialEq, C: MavericContext> Clone for NodeArgs<'n, 'p, 'c
             event: self.event,
             node: self.node,
             previous: self.previous,
+            ambient: self.ambient,
+            hot: self.hot,
         }
     }
 }
@@ -25,32 +31,51 @@ impl<'n, 'p, 'c1, N: PartialEq, C: MavericContext> NodeArgs<'n, 'p, 'c1, N, C> {
         event: SetEvent,
         node: &'n N,
         previous: Option<&'p N>,
+        ambient: Ambient<'c1>,
     ) -> Self {
         Self {
             context,
             event,
             node,
             previous,
+            ambient,
+            hot: false,
         }
     }
 
-    /// Returns true if this is a creation or undeletion, or if the context or args have changed
+    /// Returns true if this is a creation or undeletion, or if the context, args or any ambient values read have changed
     #[must_use]
     pub fn is_hot(&self) -> bool {
         match self.event {
             SetEvent::Created | SetEvent::Undeleted => true,
             SetEvent::Updated => {
-                self.context.has_changed() || self.previous.map_or(true, |p| !p.eq(self.node))
+                self.hot
+                    || self.context.has_changed()
+                    || self.previous.map_or(true, |p| !p.eq(self.node))
             }
         }
     }
 
+    /// Read a value provided by a `Provider` ancestor.
+    /// These args will be hot if that value has changed
+    pub fn ambient<T: Send + Sync + 'static>(&mut self) -> Option<&'c1 T> {
+        self.hot |= self.ambient.has_changed::<T>();
+        self.ambient.get()
+    }
+
+    /// Children must be visited if this is hot or if a provided value has changed, as they may read that value
+    pub(crate) fn should_visit_children(&self) -> bool {
+        self.is_hot() || self.ambient.any_changed()
+    }
+
     pub fn map_node<N2: PartialEq>(self, map: impl Fn(&N) -> &N2) -> NodeArgs<'n, 'p, 'c1, N2, C> {
         NodeArgs {
             node: map(self.node),
             previous: self.previous.map(map),
             context: self.context,
             event: self.event,
+            ambient: self.ambient,
+            hot: self.hot,
         }
     }
 
@@ -63,6 +88,8 @@ impl<'n, 'p, 'c1, N: PartialEq, C: MavericContext> NodeArgs<'n, 'p, 'c1, N, C> {
             previous: self.previous,
             context: map(self.context),
             event: self.event,
+            ambient: self.ambient,
+            hot: self.hot,
         }
     }
 }
@@ -0,0 +1,182 @@
+use crate::{has_changed::HasChanged, prelude::*};
+use bevy::ecs::{
+    component::Tick,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use std::ops::Deref;
+
+/// A resource which may not exist.
+/// Unlike `Option<Res<T>>` this is also marked as changed when the resource is removed
+pub struct OptionalRes<'w, T: Resource> {
+    res: Option<Res<'w, T>>,
+    has_changed: bool,
+}
+
+impl<T: Resource> HasChanged for OptionalRes<'_, T> {
+    fn has_changed(&self) -> bool {
+        self.has_changed
+    }
+}
+
+impl<'w, T: Resource> Deref for OptionalRes<'w, T> {
+    type Target = Option<Res<'w, T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.res
+    }
+}
+
+impl<T: Resource + std::fmt::Debug> std::fmt::Debug for OptionalRes<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.res.as_deref().fmt(f)
+    }
+}
+
+unsafe impl<T: Resource> ReadOnlySystemParam for OptionalRes<'_, T> {}
+
+pub struct OptionalResState<T: Resource> {
+    res_state: <Option<Res<'static, T>> as SystemParam>::State,
+    existed: bool,
+}
+
+unsafe impl<T: Resource> SystemParam for OptionalRes<'_, T> {
+    type State = OptionalResState<T>;
+    type Item<'world, 'state> = OptionalRes<'world, T>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        OptionalResState {
+            res_state: <Option<Res<'static, T>> as SystemParam>::init_state(world, system_meta),
+            existed: false,
+        }
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        let res = <Option<Res<'static, T>> as SystemParam>::get_param(
+            &mut state.res_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+
+        let has_changed = match &res {
+            Some(res) => res.is_changed() || !state.existed,
+            None => state.existed,
+        };
+        state.existed = res.is_some();
+
+        OptionalRes { res, has_changed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::{state::app::StatesPlugin, time::TimePlugin};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, States)]
+    enum Screen {
+        #[default]
+        Menu,
+        Game,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct ScreenNode(Screen);
+
+    impl MavericNode for ScreenNode {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn screens(app: &mut App) -> Vec<Screen> {
+        app.world_mut()
+            .query::<&MavericNodeComponent<ScreenNode>>()
+            .iter(app.world())
+            .map(|x| x.node.0)
+            .collect()
+    }
+
+    #[test]
+    pub fn test_optional_state() {
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = OptionalRes<'w, State<Screen>>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                if let Some(screen) = context.as_ref() {
+                    commands.add_child(0, ScreenNode(*screen.get()), &());
+                }
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, StatesPlugin));
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(screens(&mut app), vec![]);
+
+        app.init_state::<Screen>();
+        app.update();
+        app.update();
+        assert_eq!(screens(&mut app), vec![Screen::Menu]);
+
+        app.world_mut()
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Game);
+        app.update();
+        app.update();
+        assert_eq!(screens(&mut app), vec![Screen::Game]);
+
+        app.world_mut().remove_resource::<State<Screen>>();
+        app.update();
+        assert_eq!(screens(&mut app), vec![]);
+    }
+
+    #[test]
+    pub fn test_option_res_context() {
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = Option<Res<'w, State<Screen>>>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                if let Some(screen) = context {
+                    commands.add_child(0, ScreenNode(*screen.get()), &());
+                }
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, StatesPlugin));
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(screens(&mut app), vec![]);
+
+        app.init_state::<Screen>();
+        app.update();
+        app.update();
+        assert_eq!(screens(&mut app), vec![Screen::Menu]);
+
+        app.world_mut()
+            .resource_mut::<NextState<Screen>>()
+            .set(Screen::Game);
+        app.update();
+        app.update();
+        assert_eq!(screens(&mut app), vec![Screen::Game]);
+    }
+}
@@ -17,6 +17,19 @@ impl CanRegisterMaveric for App {
             self.add_plugins(ScheduledChangePlugin);
         }
 
+        self.add_event::<MavericError>();
+
+        if !self.is_plugin_added::<DuplicateKeyPlugin>() {
+            self.add_plugins(DuplicateKeyPlugin);
+        }
+
+        register_managed_entity_checks::<R>(self);
+
+        #[cfg(all(feature = "widgets", feature = "bevy_ui"))]
+        {
+            crate::widgets::scroll_view_node::register_scroll_to::<R>(self);
+        }
+
         #[cfg(feature = "tracing")]
         {
             if !self.is_plugin_added::<crate::tracing::TracingPlugin>() {
@@ -31,19 +44,28 @@ impl CanRegisterMaveric for App {
             }
         }
 
-        self.add_systems(First, sync_state::<R>.run_if(should_run::<R>));
+        self.add_systems(
+            First,
+            sync_state::<R>
+                .run_if(should_run::<R>)
+                .after(repair_managed_entities::<R>),
+        );
         self
     }
 }
 
-fn should_run<'w, 's, R: MavericRoot>(param: StaticSystemParam<R::Context<'w, 's>>) -> bool {
+#[allow(clippy::needless_pass_by_value)]
+fn should_run<'w, 's, R: MavericRoot>(
+    param: StaticSystemParam<R::Context<'w, 's>>,
+    repairs: Res<ManagedEntityRepairs<R>>,
+) -> bool {
     let inner = param.into_inner();
 
-    <R::Context<'w, 's>>::has_item_changed(&inner)
+    repairs.resync || <R::Context<'w, 's>>::has_item_changed(&inner)
 }
 
 #[allow(clippy::needless_pass_by_value)]
-fn sync_state<'w, 's, R: MavericRoot>(
+pub(crate) fn sync_state<'w, 's, R: MavericRoot>(
     mut commands: Commands,
     param: StaticSystemParam<R::Context<'w, 's>>,
     root_query: Query<(Entity, &MavericChildComponent<R>), Without<Parent>>,
@@ -52,10 +74,21 @@ fn sync_state<'w, 's, R: MavericRoot>(
 ) {
     let inner = param.into_inner();
 
-    let changed = <R::Context<'w, 's>>::has_item_changed(&inner);
+    let resync = world.resource::<ManagedEntityRepairs<R>>().resync;
+    let changed = resync || <R::Context<'w, 's>>::has_item_changed(&inner);
     if !changed {
         return;
     }
+    if resync {
+        commands.add(|world: &mut World| {
+            world.resource_mut::<ManagedEntityRepairs<R>>().resync = false;
+        });
+    }
+
+    #[cfg(feature = "tracing")]
+    {
+        crate::tracing::record_root_change::<R>(&inner);
+    }
 
     let allocator = allocator.borrow_mut();
 
@@ -174,7 +207,8 @@ mod tests {
         fn set_children<R: MavericRoot>(
             commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
         ) {
-            let Some((context,mut commands)) = commands.ignore_node().ordered_children_with_context()
+            let Some((context, mut commands)) =
+                commands.ignore_node().ordered_children_with_context()
             else {
                 return;
             };
@@ -0,0 +1,280 @@
+use crate::{has_changed::HasChanged, prelude::*};
+use bevy::ecs::{
+    archetype::Archetype,
+    component::Tick,
+    entity::EntityHashSet,
+    query::QueryFilter,
+    removal_detection::RemovedComponents,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use std::ops::Deref;
+
+/// A context which queries a component.
+/// It is marked as changed when any matched entity's component changes or is added,
+/// or when the set of matched entities changes
+pub struct QueryContext<'w, 's, T: Component, F: QueryFilter + 'static = ()> {
+    query: Query<'w, 's, &'static T, F>,
+    has_changed: bool,
+}
+
+impl<T: Component, F: QueryFilter + 'static> HasChanged for QueryContext<'_, '_, T, F> {
+    fn has_changed(&self) -> bool {
+        self.has_changed
+    }
+}
+
+impl<'w, 's, T: Component, F: QueryFilter + 'static> Deref for QueryContext<'w, 's, T, F> {
+    type Target = Query<'w, 's, &'static T, F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl<T: Component + std::fmt::Debug, F: QueryFilter + 'static> std::fmt::Debug
+    for QueryContext<'_, '_, T, F>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.query.iter()).finish()
+    }
+}
+
+unsafe impl<T: Component, F: QueryFilter + 'static> ReadOnlySystemParam
+    for QueryContext<'_, '_, T, F>
+{
+}
+
+type ChangedQuery<T, F> = Query<'static, 'static, (), (F, Changed<T>)>;
+type AddedQuery<T, F> = Query<'static, 'static, (), (F, Added<T>)>;
+type EntitiesQuery<F> = Query<'static, 'static, Entity, F>;
+
+pub struct QueryContextState<T: Component, F: QueryFilter + 'static> {
+    query_state: <Query<'static, 'static, &'static T, F> as SystemParam>::State,
+    changed_state: <ChangedQuery<T, F> as SystemParam>::State,
+    added_state: <AddedQuery<T, F> as SystemParam>::State,
+    removed_state: <RemovedComponents<'static, 'static, T> as SystemParam>::State,
+    entities_state: <EntitiesQuery<F> as SystemParam>::State,
+    /// The matched entities when this was last fetched
+    previous_entities: Option<EntityHashSet>,
+}
+
+unsafe impl<T: Component, F: QueryFilter + 'static> SystemParam for QueryContext<'_, '_, T, F> {
+    type State = QueryContextState<T, F>;
+    type Item<'world, 'state> = QueryContext<'world, 'state, T, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        QueryContextState {
+            query_state: <Query<'static, 'static, &'static T, F> as SystemParam>::init_state(
+                world,
+                system_meta,
+            ),
+            changed_state: <ChangedQuery<T, F> as SystemParam>::init_state(world, system_meta),
+            added_state: <AddedQuery<T, F> as SystemParam>::init_state(world, system_meta),
+            removed_state: <RemovedComponents<'static, 'static, T> as SystemParam>::init_state(
+                world,
+                system_meta,
+            ),
+            entities_state: <EntitiesQuery<F> as SystemParam>::init_state(world, system_meta),
+            previous_entities: None,
+        }
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        <Query<'static, 'static, &'static T, F> as SystemParam>::new_archetype(
+            &mut state.query_state,
+            archetype,
+            system_meta,
+        );
+        <ChangedQuery<T, F> as SystemParam>::new_archetype(
+            &mut state.changed_state,
+            archetype,
+            system_meta,
+        );
+        <AddedQuery<T, F> as SystemParam>::new_archetype(
+            &mut state.added_state,
+            archetype,
+            system_meta,
+        );
+        <EntitiesQuery<F> as SystemParam>::new_archetype(
+            &mut state.entities_state,
+            archetype,
+            system_meta,
+        );
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        let changed = <ChangedQuery<T, F> as SystemParam>::get_param(
+            &mut state.changed_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+        let added = <AddedQuery<T, F> as SystemParam>::get_param(
+            &mut state.added_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+        let mut removed = <RemovedComponents<'static, 'static, T> as SystemParam>::get_param(
+            &mut state.removed_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+        let entities = <EntitiesQuery<F> as SystemParam>::get_param(
+            &mut state.entities_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+
+        // Always read the removals so that they are not seen again next time
+        let was_removed = removed.read().any(|entity| {
+            state
+                .previous_entities
+                .as_ref()
+                .is_some_and(|x| x.contains(&entity))
+        });
+
+        let entities_changed = match &mut state.previous_entities {
+            Some(previous) if was_removed || !added.is_empty() => {
+                previous.clear();
+                previous.extend(entities.iter());
+                true
+            }
+            Some(previous) => {
+                // Entities can also start or stop matching the filter without `T` being added or removed
+                let mut count = 0;
+                let same = entities.iter().all(|entity| {
+                    count += 1;
+                    previous.contains(&entity)
+                }) && count == previous.len();
+                if !same {
+                    previous.clear();
+                    previous.extend(entities.iter());
+                }
+                !same
+            }
+            None => {
+                state.previous_entities = Some(entities.iter().collect());
+                true
+            }
+        };
+
+        let query = <Query<'static, 'static, &'static T, F> as SystemParam>::get_param(
+            &mut state.query_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+
+        QueryContext {
+            query,
+            has_changed: !changed.is_empty() || entities_changed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    pub fn test_query_context() {
+        #[derive(Debug, Clone, Copy, PartialEq, Component)]
+        struct Unit(u32);
+
+        #[derive(Debug, Component)]
+        struct Friendly;
+
+        #[derive(Debug)]
+        struct Root;
+
+        impl MavericRoot for Root {
+            type Context<'w, 's> = QueryContext<'w, 's, Unit, With<Friendly>>;
+
+            fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+                for unit in context.iter() {
+                    commands.add_child(unit.0, UnitNode(unit.0), &());
+                }
+            }
+        }
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct UnitNode(u32);
+
+        impl MavericNode for UnitNode {
+            type Context<'w, 's> = ();
+
+            fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+            fn set_children<R: MavericRoot>(
+                _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+            ) {
+            }
+        }
+
+        fn units(app: &mut App) -> Vec<u32> {
+            let mut units: Vec<u32> = app
+                .world_mut()
+                .query::<&MavericNodeComponent<UnitNode>>()
+                .iter(app.world())
+                .map(|x| x.node.0)
+                .collect();
+            units.sort_unstable();
+            units
+        }
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.register_maveric::<Root>();
+
+        let first = app.world_mut().spawn((Unit(1), Friendly)).id();
+        let second = app.world_mut().spawn((Unit(2), Friendly)).id();
+        let third = app.world_mut().spawn(Unit(3)).id();
+        let fourth = app.world_mut().spawn(Unit(5)).id();
+        app.update();
+        assert_eq!(units(&mut app), vec![1, 2]);
+
+        app.world_mut().get_mut::<Unit>(first).unwrap().0 = 4;
+        app.update();
+        assert_eq!(units(&mut app), vec![2, 4]);
+
+        app.world_mut().entity_mut(first).remove::<Friendly>();
+        app.update();
+        assert_eq!(units(&mut app), vec![2]);
+
+        app.world_mut().entity_mut(first).insert(Friendly);
+        app.world_mut().entity_mut(second).remove::<Friendly>();
+        app.world_mut().entity_mut(fourth).insert(Friendly);
+        app.update();
+        assert_eq!(units(&mut app), vec![4, 5]);
+
+        // Swap two matched entities for two others with the same total id
+        app.world_mut().entity_mut(first).remove::<Friendly>();
+        app.world_mut().entity_mut(fourth).remove::<Friendly>();
+        app.world_mut().entity_mut(second).insert(Friendly);
+        app.world_mut().entity_mut(third).insert(Friendly);
+        app.update();
+        assert_eq!(units(&mut app), vec![2, 3]);
+
+        app.world_mut().despawn(second);
+        app.update();
+        assert_eq!(units(&mut app), vec![3]);
+
+        app.world_mut().spawn((Unit(6), Friendly));
+        app.update();
+        assert_eq!(units(&mut app), vec![3, 6]);
+    }
+}
@@ -11,6 +11,8 @@ pub(crate) struct RootCommands<'w, 's, 'b, 'q, 'alloc, R: MavericRoot> {
     remaining_old_entities: HashMap<ChildKey, Entity, DefaultHashBuilder, &'alloc Allocator>,
     world: &'q World,
     phantom: PhantomData<R>,
+    duplicate_checker: DuplicateChecker<'alloc>,
+    held_children: HeldChildren<'alloc, Entity>,
 }
 
 impl<'w, 's, 'b, 'w1, 'q: 'w1, 'alloc, R: MavericRoot> RootCommands<'w, 's, 'b, 'q, 'alloc, R> {
@@ -34,10 +36,14 @@ impl<'w, 's, 'b, 'w1, 'q: 'w1, 'alloc, R: MavericRoot> RootCommands<'w, 's, 'b,
             remaining_old_entities,
             world,
             phantom: PhantomData,
+            duplicate_checker: DuplicateChecker::new(world, allocator),
+            held_children: HeldChildren::new(allocator),
         }
     }
 
-    pub(crate) fn finish(self) {
+    pub(crate) fn finish(mut self) {
+        self.held_children.release(self.commands);
+
         for (_key, er) in &self.remaining_old_entities {
             let _ = delete_recursive::<R>(self.commands, *er, self.world);
         }
@@ -50,8 +56,21 @@ impl<'w, 's, 'b, 'q, 'alloc, R: MavericRoot> ChildCommands
     fn remove_child(&mut self, key: impl Into<ChildKey>) {
         let key: ChildKey = key.into();
 
+        let key = match self
+            .duplicate_checker
+            .check::<R>(key, None, None, self.commands)
+        {
+            KeyCheck::Use(key) => key,
+            KeyCheck::Skip => return,
+            KeyCheck::ReplacePrevious(_) => {
+                self.discard_previous(key);
+                key
+            }
+        };
+        self.duplicate_checker.record(key, Entity::PLACEHOLDER);
+
         if let Some(entity) = self.remaining_old_entities.remove(&key) {
-            self.commands.entity(entity).despawn_recursive();
+            despawn_managed(self.commands, entity);
         }
     }
 
@@ -63,18 +82,81 @@ impl<'w, 's, 'b, 'q, 'alloc, R: MavericRoot> ChildCommands
     ) {
         let key = key.into();
 
-        if let Some(entity) = self.remaining_old_entities.remove(&key) {
-            if let Some(previous) = self.world.get::<MavericNodeComponent<NChild>>(entity) {
+        let key = match self.duplicate_checker.check::<R>(
+            key,
+            Some(type_name::<NChild>()),
+            None,
+            self.commands,
+        ) {
+            KeyCheck::Use(key) => key,
+            KeyCheck::Skip => return,
+            KeyCheck::ReplacePrevious(_) => {
+                self.discard_previous(key);
+                key
+            }
+        };
+
+        let id = if self.duplicate_checker.holds_children() {
+            let old = self.remaining_old_entities.get(&key).copied();
+            self.held_children.hold(key, old, self.world, |commands| {
+                Self::add_child_to(
+                    commands,
+                    self.world,
+                    &mut self.remaining_old_entities,
+                    key,
+                    child,
+                    context,
+                )
+            })
+        } else {
+            Self::add_child_to(
+                self.commands,
+                self.world,
+                &mut self.remaining_old_entities,
+                key,
+                child,
+                context,
+            )
+        };
+        self.duplicate_checker.record(key, id);
+    }
+}
+
+impl<'alloc, R: MavericRoot> RootCommands<'_, '_, '_, '_, 'alloc, R> {
+    /// Discard the held child which previously had this key so that its existing entity can be reused
+    fn discard_previous(&mut self, key: ChildKey) {
+        if let Some(entity) = self.held_children.discard(key, self.commands) {
+            self.remaining_old_entities.insert(key, entity);
+        }
+    }
+
+    /// Update the existing root with this key or create a new one, returning its entity
+    fn add_child_to<NChild: MavericNode>(
+        commands: &mut Commands,
+        world: &World,
+        remaining_old_entities: &mut HashMap<
+            ChildKey,
+            Entity,
+            DefaultHashBuilder,
+            &'alloc Allocator,
+        >,
+        key: ChildKey,
+        child: NChild,
+        context: &NChild::Context<'_, '_>,
+    ) -> Entity {
+        if let Some(entity) = remaining_old_entities.remove(&key) {
+            if let Some(previous) = world.get::<MavericNodeComponent<NChild>>(entity) {
                 if !child.should_recreate(&previous.node, context) {
                     update_recursive::<R, NChild>(
-                        self.commands,
+                        commands,
                         entity,
                         child,
                         context,
-                        self.world,
-                        self.remaining_old_entities.allocator(),
+                        Ambient::default(),
+                        world,
+                        remaining_old_entities.allocator(),
                     );
-                    return;
+                    return entity;
                 }
             } else {
                 warn!(
@@ -84,17 +166,18 @@ impl<'w, 's, 'b, 'q, 'alloc, R: MavericRoot> ChildCommands
             }
 
             // The node type has changed - delete this entity and readd
-            self.commands.entity(entity).despawn_recursive();
+            despawn_managed(commands, entity);
         }
 
-        let cec = self.commands.spawn_empty();
+        let cec = commands.spawn_empty();
         create_recursive::<R, NChild>(
             cec,
             child,
             context,
+            Ambient::default(),
             key,
-            self.world,
-            self.remaining_old_entities.allocator(),
-        );
+            world,
+            remaining_old_entities.allocator(),
+        )
     }
 }
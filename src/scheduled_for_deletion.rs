@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use bevy::prelude::*;
 use std::time::Duration;
 
@@ -42,7 +43,7 @@ fn handle_scheduled_for_deletion(
                     count += 1;
                 }
 
-                commands.entity(entity).despawn_recursive();
+                despawn_managed(&mut commands, entity);
             }
         }
     }
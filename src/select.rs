@@ -0,0 +1,179 @@
+use crate::{has_changed::HasChanged, prelude::*};
+use bevy::ecs::{
+    component::Tick,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use std::ops::Deref;
+
+/// A projection of part of a resource.
+/// Use `define_selector!` to create one
+pub trait Selector: Send + Sync + 'static {
+    type Resource: Resource;
+    type Output: PartialEq + Send + Sync + 'static;
+
+    fn select(resource: &Self::Resource) -> Self::Output;
+}
+
+/// Define a `Selector` from a resource type, an output type and a projection function
+/// e.g. `define_selector!(ScoreSelector, GameState, u32, |state| state.score);`
+#[macro_export]
+macro_rules! define_selector {
+    ($S:ident, $R:ty, $O:ty, $f:expr) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $S;
+
+        impl $crate::select::Selector for $S {
+            type Resource = $R;
+            type Output = $O;
+
+            fn select(resource: &Self::Resource) -> Self::Output {
+                let f: fn(&$R) -> $O = $f;
+                f(resource)
+            }
+        }
+    };
+}
+
+/// A context containing a projection of a resource.
+/// The projection is recalculated when the resource changes but this is only marked as changed when the projection changes
+pub struct Select<'w, 's, S: Selector> {
+    resource: Res<'w, S::Resource>,
+    value: &'s S::Output,
+    has_changed: bool,
+}
+
+impl<'w, S: Selector> Select<'w, '_, S> {
+    /// The whole resource.
+    /// Changes to parts of the resource outside the projection will not be detected
+    #[must_use]
+    pub const fn resource(&self) -> &Res<'w, S::Resource> {
+        &self.resource
+    }
+}
+
+impl<S: Selector> HasChanged for Select<'_, '_, S> {
+    fn has_changed(&self) -> bool {
+        self.has_changed
+    }
+}
+
+impl<S: Selector> Deref for Select<'_, '_, S> {
+    type Target = S::Output;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<S: Selector> std::fmt::Debug for Select<'_, '_, S>
+where
+    S::Output: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+unsafe impl<S: Selector> ReadOnlySystemParam for Select<'_, '_, S> {}
+
+pub struct SelectState<S: Selector> {
+    resource_state: <Res<'static, S::Resource> as SystemParam>::State,
+    value: Option<S::Output>,
+}
+
+unsafe impl<S: Selector> SystemParam for Select<'_, '_, S> {
+    type State = SelectState<S>;
+    type Item<'world, 'state> = Select<'world, 'state, S>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        SelectState {
+            resource_state: <Res<'static, S::Resource> as SystemParam>::init_state(
+                world,
+                system_meta,
+            ),
+            value: None,
+        }
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        let resource = <Res<'static, S::Resource> as SystemParam>::get_param(
+            &mut state.resource_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+
+        let has_changed = match &state.value {
+            Some(_) if !resource.is_changed() => false,
+            Some(previous) => {
+                let new_value = S::select(&resource);
+                if &new_value == previous {
+                    false
+                } else {
+                    state.value = Some(new_value);
+                    true
+                }
+            }
+            None => {
+                state.value = Some(S::select(&resource));
+                true
+            }
+        };
+
+        Select {
+            resource,
+            value: state.value.as_ref().expect("Value should be set"),
+            has_changed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{has_changed::HasChanged, prelude::*};
+
+    #[derive(Debug, Default, Resource)]
+    pub struct GameState {
+        score: u32,
+        frame: u32,
+    }
+
+    crate::define_selector!(ScoreSelector, GameState, u32, |state| state.score);
+
+    #[test]
+    pub fn test_select() {
+        #[derive(Debug, Default, Resource)]
+        struct Changes(Vec<(u32, u32, bool)>);
+
+        fn record_changes(score: Select<ScoreSelector>, mut changes: ResMut<Changes>) {
+            changes
+                .0
+                .push((*score, score.resource().frame, score.has_changed()));
+        }
+
+        let mut app = App::new();
+        app.init_resource::<GameState>();
+        app.init_resource::<Changes>();
+        app.add_systems(Update, record_changes);
+        app.update();
+
+        app.world_mut().resource_mut::<GameState>().frame = 1;
+        app.update();
+
+        app.world_mut().resource_mut::<GameState>().score = 3;
+        app.update();
+
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Changes>().0,
+            vec![(0, 0, true), (0, 1, false), (3, 1, true), (3, 1, false)]
+        );
+    }
+}
@@ -14,7 +14,9 @@ pub struct SetChildrenCommands<
     N: PartialEq,
     C: MavericContext,
     R: MavericRoot,
-> {
+> where
+    'c1: 'ec,
+{
     args: NodeArgs<'n, 'p, 'c1, N, C>,
     world: &'world World,
     ec: &'ec mut EntityCommands<'a>,
@@ -40,6 +42,24 @@ impl<'n, 'p, 'c1, 'world, 'ec, 'a, 'alloc, N: PartialEq, C: MavericContext, R: M
         }
     }
 
+    /// Split into the node args, world, entity commands and allocator
+    pub(crate) const fn into_parts(
+        self,
+    ) -> (
+        NodeArgs<'n, 'p, 'c1, N, C>,
+        &'world World,
+        &'ec mut EntityCommands<'a>,
+        &'alloc Allocator,
+    ) {
+        (self.args, self.world, self.ec, self.alloc)
+    }
+
+    /// Read a value provided by a `Provider` ancestor.
+    /// These commands will be hot if that value has changed
+    pub fn ambient<T: Send + Sync + 'static>(&mut self) -> Option<&'c1 T> {
+        self.args.ambient()
+    }
+
     #[must_use]
     pub fn ignore_node(
         self,
@@ -81,6 +101,15 @@ impl<'n, 'p, 'c1, 'world, 'ec, 'a, 'alloc, N: PartialEq, C: MavericContext, R: M
     }
 }
 
+impl<'world, N: StatefulNode, C: MavericContext, R: MavericRoot>
+    SetChildrenCommands<'_, '_, '_, 'world, '_, '_, '_, N, C, R>
+{
+    /// The local state of this node
+    pub fn local_state(&mut self) -> LocalState<'world, N::State> {
+        LocalState::get_or_init(self.world, self.ec)
+    }
+}
+
 impl<
         'n,
         'p,
@@ -138,8 +167,9 @@ impl<'n, 'p, 'c1, 'world, 'ec, 'a, 'alloc, N: PartialEq, C: MavericContext, R: M
         NodeArgs<'n, 'p, 'c1, N, C>,
         OrderedChildCommands<'ec, 'a, 'world, 'alloc, R>,
     )> {
-        if self.args.is_hot() {
-            let occ = OrderedChildCommands::<R>::new(self.ec, self.world, self.alloc);
+        if self.args.should_visit_children() {
+            let occ =
+                OrderedChildCommands::<R>::new(self.ec, self.world, self.alloc, self.args.ambient);
 
             Some((self.args, occ))
         } else {
@@ -154,8 +184,13 @@ impl<'n, 'p, 'c1, 'world, 'ec, 'a, 'alloc, N: PartialEq, C: MavericContext, R: M
         NodeArgs<'n, 'p, 'c1, N, C>,
         UnorderedChildCommands<'ec, 'a, 'world, 'alloc, R>,
     )> {
-        if self.args.is_hot() {
-            let ucc = UnorderedChildCommands::<R>::new(self.ec, self.world, self.alloc);
+        if self.args.should_visit_children() {
+            let ucc = UnorderedChildCommands::<R>::new(
+                self.ec,
+                self.world,
+                self.alloc,
+                self.args.ambient,
+            );
             Some((self.args, ucc))
         } else {
             None
@@ -77,6 +77,12 @@ impl<'n, 'p, 'c1, 'world, 'ec, 'w, 's, 'a, N: PartialEq, C: MavericContext>
 
     pub const fn finish(self) {}
 
+    /// Read a value provided by a `Provider` ancestor.
+    /// These commands will be hot if that value has changed
+    pub fn ambient<T: Send + Sync + 'static>(&mut self) -> Option<&'c1 T> {
+        self.args.ambient()
+    }
+
     #[allow(clippy::return_self_not_must_use)]
     pub fn insert_with_node_and_context<B: Bundle>(
         self,
@@ -159,6 +165,15 @@ impl<'n, 'p, 'c1, 'world, 'ec, 'w, 's, 'a, N: PartialEq, C: MavericContext>
     }
 }
 
+impl<'world, N: StatefulNode, C: MavericContext>
+    SetComponentCommands<'_, '_, '_, 'world, '_, '_, N, C>
+{
+    /// The local state of this node
+    pub fn local_state(&mut self) -> LocalState<'world, N::State> {
+        LocalState::get_or_init(self.world, self.ec)
+    }
+}
+
 impl<'n, 'p, 'c1, 'world, 'ec, 'a, N: PartialEq + IntoBundle>
     SetComponentCommands<'n, 'p, 'c1, 'world, 'ec, 'a, N, ()>
 {
@@ -1,7 +1,9 @@
-use std::sync::atomic::AtomicUsize;
+use std::sync::{atomic::AtomicUsize, Mutex};
 
 use bevy::prelude::*;
 
+use crate::{has_changed::HasChanged, prelude::*};
+
 #[derive(Debug, Default)]
 pub(crate) struct TracingPlugin;
 
@@ -15,6 +17,67 @@ pub(crate) static GRAPH_UPDATES: AtomicUsize = AtomicUsize::new(0);
 pub(crate) static SCHEDULED_DELETIONS: AtomicUsize = AtomicUsize::new(0);
 pub(crate) static SCHEDULED_CHANGES: AtomicUsize = AtomicUsize::new(0);
 pub(crate) static TRANSITIONS: AtomicUsize = AtomicUsize::new(0);
+static ROOT_CHANGES: Mutex<Vec<RootChange>> = Mutex::new(Vec::new());
+static NODE_CHANGES: Mutex<Vec<NodeChange>> = Mutex::new(Vec::new());
+
+/// Records why a root was updated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RootChange {
+    pub root_type: &'static str,
+    /// The parts of the root context which reported a change
+    pub context_changes: Vec<String>,
+}
+
+/// Records why a node became hot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeChange {
+    pub node_type: &'static str,
+    pub entity: Entity,
+    pub event: SetEvent,
+    /// Whether the node was different to its previous value
+    pub node_changed: bool,
+    /// The parts of the node context which reported a change
+    pub context_changes: Vec<String>,
+}
+
+pub(crate) fn record_root_change<R: MavericRoot>(
+    item: &<R::Context<'_, '_> as bevy::ecs::system::SystemParam>::Item<'_, '_>,
+) {
+    let mut context_changes = vec![];
+    <R::Context<'_, '_>>::item_changed_paths(item, "context", &mut context_changes);
+
+    if let Ok(mut changes) = ROOT_CHANGES.lock() {
+        changes.push(RootChange {
+            root_type: std::any::type_name::<R>(),
+            context_changes,
+        });
+    }
+}
+
+pub(crate) fn record_node_change<N: MavericNode>(
+    entity: Entity,
+    event: SetEvent,
+    node_changed: bool,
+    context: &N::Context<'_, '_>,
+) {
+    let mut context_changes = vec![];
+    if event == SetEvent::Updated {
+        context.changed_paths("context", &mut context_changes);
+        if !node_changed && context_changes.is_empty() {
+            return;
+        }
+    }
+
+    if let Ok(mut changes) = NODE_CHANGES.lock() {
+        changes.push(NodeChange {
+            node_type: std::any::type_name::<N>(),
+            entity,
+            event,
+            node_changed,
+            context_changes,
+        });
+    }
+}
 
 /// Counts maveric graph updates
 pub fn count_graph_updates() -> usize {
@@ -35,9 +98,231 @@ pub fn count_transitions() -> usize {
     TRANSITIONS.load(std::sync::atomic::Ordering::SeqCst)
 }
 
+/// The roots which were updated this frame and the context changes which caused them to update
+pub fn root_changes() -> Vec<RootChange> {
+    ROOT_CHANGES.lock().map(|x| x.clone()).unwrap_or_default()
+}
+
+/// The nodes which became hot this frame and the reasons they did
+pub fn node_changes() -> Vec<NodeChange> {
+    NODE_CHANGES.lock().map(|x| x.clone()).unwrap_or_default()
+}
+
+/// Logs the root and node changes for this frame.
+/// Add this as a system after maveric has run to find contexts which change more often than they should
+pub fn log_changes() {
+    for change in root_changes() {
+        info!(
+            "Root {} updated because {}",
+            change.root_type,
+            change.context_changes.join(", ")
+        );
+    }
+
+    for change in node_changes() {
+        let mut reasons = change.context_changes.clone();
+        if change.node_changed {
+            reasons.insert(0, "node changed".to_string());
+        }
+        if reasons.is_empty() {
+            reasons.push(format!("{:?}", change.event));
+        }
+
+        info!(
+            "Node {} ({:?}) is hot because {}",
+            change.node_type,
+            change.entity,
+            reasons.join(", ")
+        );
+    }
+}
+
 pub fn reset_tracing() {
     SCHEDULED_DELETIONS.store(0, std::sync::atomic::Ordering::SeqCst);
     SCHEDULED_CHANGES.store(0, std::sync::atomic::Ordering::SeqCst);
     GRAPH_UPDATES.store(0, std::sync::atomic::Ordering::SeqCst);
     TRANSITIONS.store(0, std::sync::atomic::Ordering::SeqCst);
+    if let Ok(mut changes) = ROOT_CHANGES.lock() {
+        changes.clear();
+    }
+    if let Ok(mut changes) = NODE_CHANGES.lock() {
+        changes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as maveric;
+    use bevy::time::TimePlugin;
+
+    #[derive(Debug, Default, Resource)]
+    struct Score(u32);
+
+    #[derive(Debug, Default, Resource)]
+    struct Level(u32);
+
+    #[derive(MavericContext)]
+    struct GameContext<'w> {
+        score: Res<'w, Score>,
+        level: Res<'w, Level>,
+    }
+
+    #[derive(Debug)]
+    struct DerivedRoot;
+
+    impl MavericRoot for DerivedRoot {
+        type Context<'w, 's> = GameContext<'w>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(0, ScoreNode(context.score.0), &());
+        }
+    }
+
+    #[derive(Debug)]
+    struct TupleRoot;
+
+    impl MavericRoot for TupleRoot {
+        type Context<'w, 's> = (Res<'w, Level>, Res<'w, Score>);
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(0, LevelNode, &context.0);
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct ScoreNode(u32);
+
+    impl MavericNode for ScoreNode {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct LevelNode;
+
+    impl MavericNode for LevelNode {
+        type Context<'w, 's> = Res<'w, Level>;
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    /// The changes recorded by this test's roots and nodes during the last sync.
+    /// Other tests run in parallel and share the buffers so their changes are filtered out
+    #[derive(Debug, Default, Resource)]
+    struct Captured {
+        roots: Vec<(&'static str, Vec<String>)>,
+        nodes: Vec<(&'static str, SetEvent, bool, Vec<String>)>,
+    }
+
+    fn is_local(type_name: &str) -> bool {
+        type_name.starts_with(module_path!())
+    }
+
+    /// Strip the type names from the change descriptions
+    fn paths(changes: &[String]) -> Vec<String> {
+        changes
+            .iter()
+            .map(|x| x.split_once(": ").map_or(x.as_str(), |x| x.0).to_string())
+            .collect()
+    }
+
+    /// Sorted by type name as the roots are synced in any order
+    fn local_root_changes() -> Vec<(&'static str, Vec<String>)> {
+        let mut changes: Vec<_> = root_changes()
+            .into_iter()
+            .filter(|x| is_local(x.root_type))
+            .map(|x| (x.root_type, paths(&x.context_changes)))
+            .collect();
+        changes.sort();
+        changes
+    }
+
+    fn local_node_changes() -> Vec<(&'static str, SetEvent, bool, Vec<String>)> {
+        node_changes()
+            .into_iter()
+            .filter(|x| is_local(x.node_type))
+            .map(|x| {
+                (
+                    x.node_type,
+                    x.event,
+                    x.node_changed,
+                    paths(&x.context_changes),
+                )
+            })
+            .collect()
+    }
+
+    fn capture(mut captured: ResMut<Captured>) {
+        captured.roots = local_root_changes();
+        captured.nodes = local_node_changes();
+    }
+
+    #[test]
+    pub fn test_tracing_changes() {
+        use std::any::type_name;
+
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        app.init_resource::<Score>()
+            .init_resource::<Level>()
+            .init_resource::<Captured>()
+            .register_maveric::<DerivedRoot>()
+            .register_maveric::<TupleRoot>()
+            .add_systems(
+                First,
+                capture
+                    .after(sync_state::<DerivedRoot>)
+                    .after(sync_state::<TupleRoot>),
+            );
+        app.update();
+
+        app.world_mut().resource_mut::<Score>().0 = 1;
+        app.update();
+
+        let captured = app.world().resource::<Captured>();
+        assert_eq!(
+            captured.roots,
+            vec![
+                (
+                    type_name::<DerivedRoot>(),
+                    vec!["context.score".to_string()]
+                ),
+                (type_name::<TupleRoot>(), vec!["context.1".to_string()]),
+            ]
+        );
+        assert_eq!(
+            captured.nodes,
+            vec![(type_name::<ScoreNode>(), SetEvent::Updated, true, vec![])]
+        );
+
+        app.world_mut().resource_mut::<Level>().0 = 1;
+        app.update();
+
+        let captured = app.world().resource::<Captured>();
+        assert_eq!(
+            captured.nodes,
+            vec![(
+                type_name::<LevelNode>(),
+                SetEvent::Updated,
+                false,
+                vec!["context".to_string()]
+            )]
+        );
+
+        // The buffers are cleared at the end of every frame
+        assert_eq!(local_root_changes(), vec![]);
+        assert_eq!(local_node_changes(), vec![]);
+    }
 }
@@ -0,0 +1,65 @@
+use crate::has_changed::HasChanged;
+use bevy::ecs::{
+    archetype::Archetype,
+    component::Tick,
+    system::{ReadOnlySystemParam, SystemMeta, SystemParam, SystemParamItem},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+use std::ops::Deref;
+
+/// A system param which is never marked as changed.
+///
+/// Use this in the argument of a `Memo` or `Cached` value to read data without recalculating the value when that data changes.
+/// e.g. `(Res<'w, Settings>, Untracked<'w, 's, Query<'static, 'static, &'static Transform>>)`
+pub struct Untracked<'w, 's, P: ReadOnlySystemParam>(SystemParamItem<'w, 's, P>);
+
+impl<P: ReadOnlySystemParam> HasChanged for Untracked<'_, '_, P> {
+    fn has_changed(&self) -> bool {
+        false
+    }
+}
+
+impl<'w, 's, P: ReadOnlySystemParam> Deref for Untracked<'w, 's, P> {
+    type Target = SystemParamItem<'w, 's, P>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'w, 's, P: ReadOnlySystemParam> std::fmt::Debug for Untracked<'w, 's, P>
+where
+    SystemParamItem<'w, 's, P>: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+unsafe impl<P: ReadOnlySystemParam + 'static> ReadOnlySystemParam for Untracked<'_, '_, P> {}
+
+unsafe impl<P: ReadOnlySystemParam + 'static> SystemParam for Untracked<'_, '_, P> {
+    type State = P::State;
+    type Item<'world, 'state> = Untracked<'world, 'state, P>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        P::init_state(world, system_meta)
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        P::new_archetype(state, archetype, system_meta);
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'world>,
+        change_tick: Tick,
+    ) -> Self::Item<'world, 'state> {
+        Untracked(P::get_param(state, system_meta, world, change_tick))
+    }
+}
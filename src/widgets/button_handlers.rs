@@ -0,0 +1,344 @@
+use crate::prelude::*;
+use bevy::{ecs::world::Command, ui::UiSystem};
+use std::{any::Any, sync::Arc};
+
+#[derive(Debug, Default)]
+pub struct ButtonHandlersPlugin;
+
+impl Plugin for ButtonHandlersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, dispatch_button_handlers.after(UiSystem::Focus));
+    }
+}
+
+/// Things to do when a button is interacted with
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+pub struct ButtonHandlers {
+    pub on_press: Option<ButtonHandler>,
+    pub on_release: Option<ButtonHandler>,
+    pub on_hover_enter: Option<ButtonHandler>,
+    pub on_hover_exit: Option<ButtonHandler>,
+}
+
+impl ButtonHandlers {
+    #[must_use]
+    pub fn on_press(mut self, handler: ButtonHandler) -> Self {
+        self.on_press = Some(handler);
+        self
+    }
+
+    #[must_use]
+    pub fn on_release(mut self, handler: ButtonHandler) -> Self {
+        self.on_release = Some(handler);
+        self
+    }
+
+    #[must_use]
+    pub fn on_hover_enter(mut self, handler: ButtonHandler) -> Self {
+        self.on_hover_enter = Some(handler);
+        self
+    }
+
+    #[must_use]
+    pub fn on_hover_exit(mut self, handler: ButtonHandler) -> Self {
+        self.on_hover_exit = Some(handler);
+        self
+    }
+}
+
+/// Something to do when a button is interacted with.
+///
+/// Handlers are compared by value so that a button node is not hot just because its handlers were recreated
+#[derive(Clone)]
+pub struct ButtonHandler(Arc<dyn ErasedHandler>);
+
+impl ButtonHandler {
+    /// Send an event
+    pub fn send_event<E: Event + Clone + PartialEq + std::fmt::Debug>(event: E) -> Self {
+        Self(Arc::new(SendEventHandler(event)))
+    }
+
+    /// Apply a command
+    pub fn command<C: Command + Sync + Clone + PartialEq + std::fmt::Debug>(command: C) -> Self {
+        Self(Arc::new(CommandHandler(command)))
+    }
+
+    /// Update a resource
+    pub fn update_resource<R: Resource>(update: fn(&mut R)) -> Self {
+        Self::update_resource_with(update, |resource, update| update(resource))
+    }
+
+    /// Update a resource using some data
+    pub fn update_resource_with<
+        R: Resource,
+        T: PartialEq + Clone + Send + Sync + std::fmt::Debug + 'static,
+    >(
+        data: T,
+        update: fn(&mut R, &T),
+    ) -> Self {
+        Self(Arc::new(UpdateResourceHandler { data, update }))
+    }
+
+    pub(crate) fn run(&self, commands: &mut Commands) {
+        self.0.run(commands);
+    }
+}
+
+impl PartialEq for ButtonHandler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.dyn_eq(other.0.as_any())
+    }
+}
+
+impl std::fmt::Debug for ButtonHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+trait ErasedHandler: Send + Sync + std::fmt::Debug + 'static {
+    fn run(&self, commands: &mut Commands);
+    fn as_any(&self) -> &dyn Any;
+    fn dyn_eq(&self, other: &dyn Any) -> bool;
+}
+
+#[derive(Debug, PartialEq)]
+struct SendEventHandler<E>(E);
+
+impl<E: Event + Clone + PartialEq + std::fmt::Debug> ErasedHandler for SendEventHandler<E> {
+    fn run(&self, commands: &mut Commands) {
+        let event = self.0.clone();
+        commands.add(move |world: &mut World| {
+            world.send_event(event);
+        });
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>() == Some(self)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct CommandHandler<C>(C);
+
+impl<C: Command + Sync + Clone + PartialEq + std::fmt::Debug> ErasedHandler for CommandHandler<C> {
+    fn run(&self, commands: &mut Commands) {
+        commands.add(self.0.clone());
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>() == Some(self)
+    }
+}
+
+struct UpdateResourceHandler<R, T> {
+    data: T,
+    update: fn(&mut R, &T),
+}
+
+impl<R, T: std::fmt::Debug> std::fmt::Debug for UpdateResourceHandler<R, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateResourceHandler")
+            .field("resource", &std::any::type_name::<R>())
+            .field("data", &self.data)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Resource, T: PartialEq + Clone + Send + Sync + std::fmt::Debug + 'static> ErasedHandler
+    for UpdateResourceHandler<R, T>
+{
+    fn run(&self, commands: &mut Commands) {
+        let data = self.data.clone();
+        let update = self.update;
+        commands.add(move |world: &mut World| {
+            if let Some(mut resource) = world.get_resource_mut::<R>() {
+                update(&mut resource, &data);
+            }
+        });
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<Self>().is_some_and(|other| {
+            other.data == self.data && std::ptr::fn_addr_eq(other.update, self.update)
+        })
+    }
+}
+
+/// The interaction of a button when its handlers were last dispatched
+#[derive(Debug, Component)]
+pub(crate) struct PreviousInteraction(Interaction);
+
+#[allow(clippy::needless_pass_by_value)]
+fn dispatch_button_handlers(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Interaction,
+            &ButtonHandlers,
+            Option<&mut PreviousInteraction>,
+        ),
+        Changed<Interaction>,
+    >,
+) {
+    for (entity, interaction, handlers, previous) in &mut query {
+        let previous = if let Some(mut previous) = previous {
+            std::mem::replace(&mut previous.0, *interaction)
+        } else {
+            commands
+                .entity(entity)
+                .try_insert(PreviousInteraction(*interaction));
+            Interaction::None
+        };
+
+        let handlers_to_run = [
+            (
+                previous == Interaction::None && *interaction != Interaction::None,
+                &handlers.on_hover_enter,
+            ),
+            (
+                previous != Interaction::Pressed && *interaction == Interaction::Pressed,
+                &handlers.on_press,
+            ),
+            (
+                previous == Interaction::Pressed && *interaction != Interaction::Pressed,
+                &handlers.on_release,
+            ),
+            (
+                previous != Interaction::None && *interaction == Interaction::None,
+                &handlers.on_hover_exit,
+            ),
+        ];
+
+        for (should_run, handler) in handlers_to_run {
+            if let (true, Some(handler)) = (should_run, handler) {
+                handler.run(&mut commands);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+
+    #[derive(Debug, Clone, PartialEq, Event)]
+    struct Pressed(u32);
+
+    #[derive(Debug, Default, Resource)]
+    struct Counter {
+        hovers: u32,
+        releases: u32,
+    }
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Counter>;
+
+        fn set_children(_context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(
+                0,
+                ButtonNode {
+                    style: Style::default(),
+                    visibility: Visibility::Visible,
+                    border_color: Color::BLACK,
+                    background_color: Color::WHITE,
+                    border_radius: BorderRadius::default(),
+                    marker: (),
+                    handlers: ButtonHandlers::default()
+                        .on_press(ButtonHandler::send_event(Pressed(7)))
+                        .on_release(ButtonHandler::update_resource(|c: &mut Counter| {
+                            c.releases += 1;
+                        }))
+                        .on_hover_enter(ButtonHandler::update_resource_with(
+                            2,
+                            |c: &mut Counter, n| c.hovers += n,
+                        )),
+                    styles: ButtonStyles::default(),
+                    disabled: false,
+                    children: (Label,),
+                },
+                &(),
+            );
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label;
+
+    impl MavericNode for Label {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn set_interaction(app: &mut App, interaction: Interaction) {
+        let world = app.world_mut();
+        *world.query::<&mut Interaction>().single_mut(world) = interaction;
+        app.update();
+    }
+
+    fn pressed_events(app: &mut App) -> Vec<Pressed> {
+        app.world_mut()
+            .resource_mut::<Events<Pressed>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    pub fn test_button_handlers() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.init_resource::<Counter>();
+        app.add_event::<Pressed>();
+        app.register_maveric::<Root>();
+        app.update();
+
+        set_interaction(&mut app, Interaction::Hovered);
+        assert_eq!(app.world().resource::<Counter>().hovers, 2);
+        assert_eq!(pressed_events(&mut app), vec![]);
+
+        set_interaction(&mut app, Interaction::Pressed);
+        assert_eq!(pressed_events(&mut app), vec![Pressed(7)]);
+        assert_eq!(app.world().resource::<Counter>().releases, 0);
+
+        set_interaction(&mut app, Interaction::Hovered);
+        assert_eq!(app.world().resource::<Counter>().releases, 1);
+        assert_eq!(app.world().resource::<Counter>().hovers, 2);
+    }
+
+    #[test]
+    pub fn test_handler_equality() {
+        let a = ButtonHandler::update_resource_with(1, |c: &mut Counter, n| c.hovers += n);
+        assert_eq!(a, a.clone());
+        assert_eq!(
+            ButtonHandler::send_event(Pressed(1)),
+            ButtonHandler::send_event(Pressed(1))
+        );
+        assert_ne!(
+            ButtonHandler::send_event(Pressed(1)),
+            ButtonHandler::send_event(Pressed(2))
+        );
+    }
+}
@@ -9,9 +9,58 @@ pub struct ButtonNode<Marker: IntoBundle, S: IntoBundle<B = Style>, C: ChildTupl
     pub background_color: Color,
     pub border_radius: BorderRadius,
     pub marker: Marker,
+    pub handlers: ButtonHandlers,
+    pub styles: ButtonStyles,
+    /// Disabled buttons do not respond to interaction
+    pub disabled: bool,
     pub children: C,
 }
 
+impl<Marker: IntoBundle, S: IntoBundle<B = Style>, C: ChildTuple> ButtonNode<Marker, S, C> {
+    /// A button with no handlers or styles which is not disabled
+    #[must_use]
+    pub fn new(
+        style: S,
+        visibility: Visibility,
+        border_color: Color,
+        background_color: Color,
+        border_radius: BorderRadius,
+        marker: Marker,
+        children: C,
+    ) -> Self {
+        Self {
+            style,
+            visibility,
+            border_color,
+            background_color,
+            border_radius,
+            marker,
+            handlers: ButtonHandlers::default(),
+            styles: ButtonStyles::default(),
+            disabled: false,
+            children,
+        }
+    }
+
+    #[must_use]
+    pub fn with_handlers(mut self, handlers: ButtonHandlers) -> Self {
+        self.handlers = handlers;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_styles(mut self, styles: ButtonStyles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
 impl<Marker: IntoBundle, S: IntoBundle<B = Style>, C: ChildTuple> MavericNode
     for ButtonNode<Marker, S, C>
 {
@@ -24,17 +73,51 @@ impl<Marker: IntoBundle, S: IntoBundle<B = Style>, C: ChildTuple> MavericNode
         commands.node_to_bundle(|x| &x.style);
         commands.node_to_bundle(|x| &x.visibility);
         commands.node_to_bundle(|x| &x.marker);
+        commands.node_to_bundle(|x| &x.handlers);
         commands.node_to_bundle(|x| &x.border_radius);
 
         commands.scope(|commands| {
             commands
-                .map_node(|x| &x.background_color)
-                .insert_with_node(|color| BackgroundColor(*color));
-        });
-        commands.scope(|commands| {
-            commands
-                .map_node(|x| &x.border_color)
-                .insert_with_node(|color| BorderColor(*color));
+                .advanced(|args, commands| {
+                    if !args.is_hot() {
+                        return;
+                    }
+                    let node = args.node;
+                    let appearance = ButtonAppearance {
+                        normal: ButtonVariant {
+                            background_color: node.background_color,
+                            border_color: node.border_color,
+                            scale: 1.0,
+                        },
+                        styles: node.styles,
+                        disabled: node.disabled,
+                    };
+                    if args.event == SetEvent::Created {
+                        let interaction =
+                            commands.get::<Interaction>().copied().unwrap_or_default();
+                        let focused = commands.get::<Focused>().is_some_and(|f| f.0);
+                        let variant = appearance.variant(interaction, focused);
+                        commands.insert((
+                            appearance,
+                            BackgroundColor(variant.background_color),
+                            BorderColor(variant.border_color),
+                        ));
+                    } else {
+                        // The colors are updated, with any transition, when the appearance changes
+                        commands.insert(appearance);
+                    }
+
+                    let was_disabled = args.previous.is_some_and(|p| p.disabled);
+                    if node.disabled && (args.event == SetEvent::Created || !was_disabled) {
+                        commands.remove::<(
+                            Interaction,
+                            crate::widgets::button_handlers::PreviousInteraction,
+                        )>();
+                    } else if !node.disabled && was_disabled {
+                        commands.insert(Interaction::default());
+                    }
+                })
+                .finish();
         });
     }
 
@@ -0,0 +1,297 @@
+use crate::prelude::*;
+use crate::transition::speed::{LinearSpeed, ScalarSpeed};
+use bevy::ui::UiSystem;
+
+#[derive(Debug, Default)]
+pub struct ButtonStylesPlugin;
+
+impl Plugin for ButtonStylesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, apply_button_appearance.after(UiSystem::Focus));
+    }
+}
+
+/// How a button looks in one interaction state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonVariant {
+    pub background_color: Color,
+    pub border_color: Color,
+    pub scale: f32,
+}
+
+/// How fast a button moves between variants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonTransition {
+    pub color_speed: ScalarSpeed,
+    pub scale_speed: LinearSpeed,
+    pub ease: Option<Ease>,
+}
+
+/// How a button looks when it is hovered, pressed, focused or disabled.
+///
+/// Missing variants fall back to the normal appearance (pressed falls back to hovered first).
+/// If there is a transition you must register the `BackgroundColorLens`, `BorderColorLens` and `TransformScaleLens` transitions
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ButtonStyles {
+    pub hovered: Option<ButtonVariant>,
+    pub pressed: Option<ButtonVariant>,
+    pub focused: Option<ButtonVariant>,
+    pub disabled: Option<ButtonVariant>,
+    pub transition: Option<ButtonTransition>,
+}
+
+impl ButtonStyles {
+    /// Whether any variant changes the scale of the button
+    fn uses_scale(&self) -> bool {
+        [self.hovered, self.pressed, self.focused, self.disabled]
+            .into_iter()
+            .flatten()
+            .any(|variant| (variant.scale - 1.0).abs() > f32::EPSILON)
+    }
+}
+
+/// The appearance of a button in every state. This is inserted by `ButtonNode`
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct ButtonAppearance {
+    pub normal: ButtonVariant,
+    pub styles: ButtonStyles,
+    pub disabled: bool,
+}
+
+impl ButtonAppearance {
+    /// The variant to show for a particular interaction and focus
+    #[must_use]
+    pub fn variant(&self, interaction: Interaction, focused: bool) -> ButtonVariant {
+        if self.disabled {
+            return self.styles.disabled.unwrap_or(self.normal);
+        }
+        match interaction {
+            Interaction::Pressed => self
+                .styles
+                .pressed
+                .or(self.styles.hovered)
+                .unwrap_or(self.normal),
+            Interaction::Hovered => self.styles.hovered.unwrap_or(self.normal),
+            Interaction::None if focused => self.styles.focused.unwrap_or(self.normal),
+            Interaction::None => self.normal,
+        }
+    }
+}
+
+type ChangedAppearanceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, ButtonAppearance>,
+        Option<&'static Interaction>,
+        Option<&'static Focused>,
+    ),
+    Or<(
+        Changed<ButtonAppearance>,
+        Changed<Interaction>,
+        Changed<Focused>,
+    )>,
+>;
+
+#[allow(clippy::needless_pass_by_value)]
+fn apply_button_appearance(mut commands: Commands, query: ChangedAppearanceQuery, world: &World) {
+    for (entity, appearance, interaction, focused) in &query {
+        let variant = appearance.variant(
+            interaction.copied().unwrap_or_default(),
+            focused.is_some_and(|f| f.0),
+        );
+        let uses_scale = appearance.styles.uses_scale();
+        let Some(mut ec) = commands.get_entity(entity) else {
+            continue;
+        };
+
+        match appearance.styles.transition {
+            // The button node sets the colors when it is created
+            _ if appearance.is_added() => {}
+            Some(transition) => {
+                let mut commands = ComponentCommands::new(&mut ec, world, SetEvent::Updated);
+                commands.transition_value::<BackgroundColorLens>(
+                    variant.background_color,
+                    transition.color_speed,
+                    transition.ease,
+                );
+                commands.transition_value::<BorderColorLens>(
+                    variant.border_color,
+                    transition.color_speed,
+                    transition.ease,
+                );
+                if uses_scale {
+                    commands.transition_value::<TransformScaleLens>(
+                        Vec3::splat(variant.scale),
+                        transition.scale_speed,
+                        transition.ease,
+                    );
+                }
+                continue;
+            }
+            None => {
+                ec.try_insert((
+                    BackgroundColor(variant.background_color),
+                    BorderColor(variant.border_color),
+                ));
+            }
+        }
+
+        if uses_scale {
+            ec.add(move |entity: Entity, world: &mut World| {
+                if let Some(mut transform) = world.get_mut::<Transform>(entity) {
+                    transform.scale = Vec3::splat(variant.scale);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use std::time::Duration;
+
+    const NORMAL: Color = Color::BLACK;
+    const HOVERED: Color = Color::WHITE;
+    const DISABLED: Color = Color::srgb(0.5, 0.5, 0.5);
+
+    #[derive(Debug, Default, Resource)]
+    struct Disabled(bool);
+
+    #[derive(Debug, Default, Resource)]
+    struct Transition(Option<ButtonTransition>);
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = (Res<'w, Disabled>, Res<'w, Transition>);
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            let variant = |color| ButtonVariant {
+                background_color: color,
+                border_color: color,
+                scale: 1.5,
+            };
+            commands.add_child(
+                0,
+                ButtonNode {
+                    style: Style::default(),
+                    visibility: Visibility::Visible,
+                    border_color: NORMAL,
+                    background_color: NORMAL,
+                    border_radius: BorderRadius::default(),
+                    marker: (),
+                    handlers: ButtonHandlers::default(),
+                    styles: ButtonStyles {
+                        hovered: Some(variant(HOVERED)),
+                        disabled: Some(variant(DISABLED)),
+                        transition: context.1 .0,
+                        ..default()
+                    },
+                    disabled: context.0 .0,
+                    children: (Label,),
+                },
+                &(),
+            );
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label;
+
+    impl MavericNode for Label {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn background(app: &mut App) -> (Color, f32) {
+        let world = app.world_mut();
+        let (color, transform) = world
+            .query::<(&BackgroundColor, &Transform)>()
+            .single(world);
+        (color.0, transform.scale.x)
+    }
+
+    fn set_interaction(app: &mut App, interaction: Interaction) {
+        let world = app.world_mut();
+        *world.query::<&mut Interaction>().single_mut(world) = interaction;
+        app.update();
+    }
+
+    #[test]
+    pub fn test_button_styles() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.init_resource::<Disabled>();
+        app.init_resource::<Transition>();
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(background(&mut app), (NORMAL, 1.0));
+
+        set_interaction(&mut app, Interaction::Hovered);
+        assert_eq!(background(&mut app), (HOVERED, 1.5));
+
+        set_interaction(&mut app, Interaction::None);
+        assert_eq!(background(&mut app), (NORMAL, 1.0));
+
+        app.world_mut().resource_mut::<Disabled>().0 = true;
+        app.update();
+        assert_eq!(background(&mut app), (DISABLED, 1.5));
+        let world = app.world_mut();
+        assert_eq!(
+            world.query::<&Interaction>().iter(world).count(),
+            0,
+            "Disabled buttons should not be interactive"
+        );
+
+        app.world_mut().resource_mut::<Disabled>().0 = false;
+        app.update();
+        assert_eq!(background(&mut app), (NORMAL, 1.0));
+        set_interaction(&mut app, Interaction::Hovered);
+        assert_eq!(background(&mut app), (HOVERED, 1.5));
+    }
+
+    #[test]
+    pub fn test_button_styles_transition() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.register_transition::<BackgroundColorLens>();
+        app.register_transition::<BorderColorLens>();
+        app.register_transition::<TransformScaleLens>();
+        app.init_resource::<Disabled>();
+        app.insert_resource(Transition(Some(ButtonTransition {
+            color_speed: ScalarSpeed::new(1.0),
+            scale_speed: LinearSpeed::new(1.0),
+            ease: None,
+        })));
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(background(&mut app).0, NORMAL);
+
+        // Changing the node should not snap to the new colors
+        app.world_mut().resource_mut::<Disabled>().0 = true;
+        app.update();
+        app.update();
+        let (color, _) = background(&mut app);
+        assert_ne!(color, NORMAL);
+        assert_ne!(color, DISABLED);
+
+        for _ in 0..20 {
+            app.update();
+        }
+        assert_eq!(background(&mut app), (DISABLED, 1.5));
+    }
+}
@@ -70,6 +70,7 @@ impl<Child: MavericNode, F: Send + Sync + 'static + Fn(u32) -> Option<Child>> Ma
             event: _event,
             node,
             previous,
+            ..
         } = args;
 
         let Some(center_page) = (node.get_child)(node.current_page) else {
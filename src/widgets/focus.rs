@@ -0,0 +1,401 @@
+use crate::prelude::*;
+use bevy::{
+    input::{
+        gamepad::{GamepadButton, GamepadButtonType},
+        InputSystem,
+    },
+    ui::UiSystem,
+};
+
+/// Moves focus between `Focusable` entities using the keyboard, gamepad or `FocusNavigation` events.
+///
+/// Arrow keys and the d-pad move focus. Enter, space and the south gamepad button activate the focused button
+#[derive(Debug, Default)]
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Focus>()
+            .add_event::<FocusNavigation>()
+            .add_systems(
+                PreUpdate,
+                (read_focus_input, navigate_focus, sync_focused)
+                    .chain()
+                    .after(InputSystem)
+                    .before(UiSystem::Focus),
+            );
+    }
+}
+
+/// The key of the focused entity.
+///
+/// Focus is stored by key so it is kept when the focused node is re-rendered or recreated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Resource)]
+pub struct Focus {
+    pub focused: Option<ChildKey>,
+}
+
+impl Focus {
+    pub fn focus(&mut self, key: impl Into<ChildKey>) {
+        self.focused = Some(key.into());
+    }
+
+    pub const fn clear(&mut self) {
+        self.focused = None;
+    }
+
+    #[must_use]
+    pub fn is_focused(&self, key: impl Into<ChildKey>) -> bool {
+        self.focused == Some(key.into())
+    }
+}
+
+/// An entity which can be focused.
+///
+/// Add this to the marker of a `ButtonNode` to make it focusable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct Focusable {
+    pub key: ChildKey,
+    pub neighbours: FocusNeighbours,
+}
+
+impl Focusable {
+    pub fn new(key: impl Into<ChildKey>) -> Self {
+        Self {
+            key: key.into(),
+            neighbours: FocusNeighbours::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_neighbours(mut self, neighbours: FocusNeighbours) -> Self {
+        self.neighbours = neighbours;
+        self
+    }
+}
+
+/// The keys to move focus to in each direction.
+/// Missing neighbours are found from the layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FocusNeighbours {
+    pub up: Option<ChildKey>,
+    pub down: Option<ChildKey>,
+    pub left: Option<ChildKey>,
+    pub right: Option<ChildKey>,
+}
+
+impl FocusNeighbours {
+    const fn get(&self, direction: FocusDirection) -> Option<ChildKey> {
+        match direction {
+            FocusDirection::Up => self.up,
+            FocusDirection::Down => self.down,
+            FocusDirection::Left => self.left,
+            FocusDirection::Right => self.right,
+        }
+    }
+}
+
+/// Whether this entity is focused. This is kept in sync with `Focus` for every `Focusable` entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+pub struct Focused(pub bool);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FocusDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FocusDirection {
+    /// The direction in UI coordinates, where y points down
+    const fn vector(self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::NEG_Y,
+            Self::Down => Vec2::Y,
+            Self::Left => Vec2::NEG_X,
+            Self::Right => Vec2::X,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event)]
+pub enum FocusNavigation {
+    Move(FocusDirection),
+    /// Activate the focused button
+    Confirm,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn read_focus_input(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    gamepad_buttons: Option<Res<ButtonInput<GamepadButton>>>,
+    text_inputs: Query<&TextInputState>,
+    mut events: EventWriter<FocusNavigation>,
+) {
+    // The keyboard belongs to the text input being edited
+    let editing = text_inputs.iter().any(|state| state.active);
+    if let Some(keys) = keys.filter(|_| !editing) {
+        for key in keys.get_just_pressed() {
+            let navigation = match key {
+                KeyCode::ArrowUp => FocusNavigation::Move(FocusDirection::Up),
+                KeyCode::ArrowDown => FocusNavigation::Move(FocusDirection::Down),
+                KeyCode::ArrowLeft => FocusNavigation::Move(FocusDirection::Left),
+                KeyCode::ArrowRight => FocusNavigation::Move(FocusDirection::Right),
+                KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space => FocusNavigation::Confirm,
+                _ => continue,
+            };
+            events.send(navigation);
+        }
+    }
+
+    if let Some(gamepad_buttons) = gamepad_buttons {
+        for button in gamepad_buttons.get_just_pressed() {
+            let navigation = match button.button_type {
+                GamepadButtonType::DPadUp => FocusNavigation::Move(FocusDirection::Up),
+                GamepadButtonType::DPadDown => FocusNavigation::Move(FocusDirection::Down),
+                GamepadButtonType::DPadLeft => FocusNavigation::Move(FocusDirection::Left),
+                GamepadButtonType::DPadRight => FocusNavigation::Move(FocusDirection::Right),
+                GamepadButtonType::South => FocusNavigation::Confirm,
+                _ => continue,
+            };
+            events.send(navigation);
+        }
+    }
+}
+
+type FocusableQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Focusable,
+        &'static GlobalTransform,
+        Option<&'static ButtonAppearance>,
+        Option<&'static ButtonHandlers>,
+    ),
+    Without<ScheduledForDeletion>,
+>;
+
+/// Whether `entity` is `ancestor` or one of its descendants
+fn is_within(entity: Entity, ancestor: Entity, parents: &Query<&Parent>) -> bool {
+    std::iter::successors(Some(entity), |e| parents.get(*e).ok().map(Parent::get))
+        .any(|e| e == ancestor)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn navigate_focus(
+    mut commands: Commands,
+    mut events: EventReader<FocusNavigation>,
+    mut focus: ResMut<Focus>,
+    query: FocusableQuery,
+    modal_layers: ModalLayerQuery,
+    parents: Query<&Parent>,
+) {
+    // Focus is trapped inside the top dialog
+    let trap = top_modal_layer(&modal_layers).map(|(entity, _)| entity);
+    let reachable = |entity: Entity| trap.is_none_or(|trap| is_within(entity, trap, &parents));
+
+    for navigation in events.read() {
+        let candidates = query
+            .iter()
+            .filter(|(entity, ..)| reachable(*entity))
+            .filter(|(_, _, _, appearance, _)| !appearance.is_some_and(|a| a.disabled));
+        let current = focus
+            .focused
+            .and_then(|key| query.iter().find(|(_, f, ..)| f.key == key))
+            .filter(|(entity, ..)| reachable(*entity));
+
+        match (navigation, current) {
+            (FocusNavigation::Confirm, Some((_, _, _, appearance, Some(handlers)))) => {
+                if appearance.is_some_and(|a| a.disabled) {
+                    continue;
+                }
+                for handler in [&handlers.on_press, &handlers.on_release]
+                    .into_iter()
+                    .flatten()
+                {
+                    handler.run(&mut commands);
+                }
+            }
+            (FocusNavigation::Confirm, _) => {}
+            (FocusNavigation::Move(direction), Some((_, current, transform, ..))) => {
+                let explicit = current.neighbours.get(*direction).filter(|key| {
+                    query
+                        .iter()
+                        .any(|(entity, f, ..)| f.key == *key && reachable(entity))
+                });
+
+                let next = explicit.or_else(|| {
+                    let position = transform.translation().truncate();
+                    let direction = direction.vector();
+                    candidates
+                        .filter(|(_, f, ..)| f.key != current.key)
+                        .filter_map(|(_, f, t, ..)| {
+                            let delta = t.translation().truncate() - position;
+                            let along = delta.dot(direction);
+                            (along > 0.0).then(|| {
+                                (f.key, delta.perp_dot(direction).abs().mul_add(2.0, along))
+                            })
+                        })
+                        .min_by(|a, b| a.1.total_cmp(&b.1))
+                        .map(|(key, _)| key)
+                });
+
+                if let Some(next) = next {
+                    focus.focused = Some(next);
+                }
+            }
+            (FocusNavigation::Move(_), None) => {
+                // Nothing reachable is focused so focus the top left entity
+                let first = candidates
+                    .map(|(_, f, t, ..)| (f.key, t.translation()))
+                    .min_by(|a, b| a.1.y.total_cmp(&b.1.y).then(a.1.x.total_cmp(&b.1.x)));
+                if let Some((key, _)) = first {
+                    focus.focused = Some(key);
+                }
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sync_focused(
+    mut commands: Commands,
+    focus: Res<Focus>,
+    mut query: Query<(Entity, &Focusable, Option<&mut Focused>)>,
+) {
+    for (entity, focusable, focused) in &mut query {
+        let is_focused = focus.focused == Some(focusable.key);
+        match focused {
+            Some(mut focused) => {
+                focused.set_if_neq(Focused(is_focused));
+            }
+            None => {
+                commands.entity(entity).try_insert(Focused(is_focused));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, with_bundle::CanWithBundle};
+    use bevy::time::TimePlugin;
+
+    const NORMAL: Color = Color::BLACK;
+    const FOCUSED: Color = Color::WHITE;
+
+    #[derive(Debug, Default, Resource)]
+    struct Pressed(Vec<&'static str>);
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Pressed>;
+
+        fn set_children(_context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            for (index, key) in ["top", "middle", "bottom"].into_iter().enumerate() {
+                let mut focusable = Focusable::new(key);
+                if key == "top" {
+                    focusable.neighbours.up = Some("bottom".into());
+                }
+                let node = ButtonNode {
+                    style: Style::default(),
+                    visibility: Visibility::Visible,
+                    border_color: NORMAL,
+                    background_color: NORMAL,
+                    border_radius: BorderRadius::default(),
+                    marker: focusable,
+                    handlers: ButtonHandlers::default().on_press(
+                        ButtonHandler::update_resource_with(key, |p: &mut Pressed, key| {
+                            p.0.push(key);
+                        }),
+                    ),
+                    styles: ButtonStyles {
+                        focused: Some(ButtonVariant {
+                            background_color: FOCUSED,
+                            border_color: FOCUSED,
+                            scale: 1.0,
+                        }),
+                        ..default()
+                    },
+                    disabled: false,
+                    children: (Label,),
+                };
+                #[allow(clippy::cast_precision_loss)]
+                let y = index as f32 * 100.0;
+                commands.add_child(
+                    key,
+                    node.with_bundle(GlobalTransform::from_xyz(0.0, y, 0.0)),
+                    &(),
+                );
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label;
+
+    impl MavericNode for Label {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn navigate(app: &mut App, navigation: FocusNavigation) {
+        app.world_mut().send_event(navigation);
+        app.update();
+    }
+
+    fn focused_color(app: &mut App) -> Vec<(ChildKey, Color)> {
+        let world = app.world_mut();
+        let mut result: Vec<_> = world
+            .query::<(&Focusable, &BackgroundColor)>()
+            .iter(world)
+            .filter(|(_, color)| color.0 == FOCUSED)
+            .map(|(f, color)| (f.key, color.0))
+            .collect();
+        result.sort_by_key(|x| x.0);
+        result
+    }
+
+    #[test]
+    pub fn test_focus_navigation() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.init_resource::<Pressed>();
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(app.world().resource::<Focus>().focused, None);
+
+        navigate(&mut app, FocusNavigation::Move(FocusDirection::Down));
+        assert!(app.world().resource::<Focus>().is_focused("top"));
+        app.update();
+        assert_eq!(focused_color(&mut app), vec![("top".into(), FOCUSED)]);
+
+        navigate(&mut app, FocusNavigation::Move(FocusDirection::Down));
+        assert!(app.world().resource::<Focus>().is_focused("middle"));
+
+        navigate(&mut app, FocusNavigation::Confirm);
+        assert_eq!(app.world().resource::<Pressed>().0, vec!["middle"]);
+
+        // The root is re-rendered because `Pressed` changed but focus is kept
+        app.update();
+        assert!(app.world().resource::<Focus>().is_focused("middle"));
+        assert_eq!(focused_color(&mut app), vec![("middle".into(), FOCUSED)]);
+
+        navigate(&mut app, FocusNavigation::Move(FocusDirection::Up));
+        assert!(app.world().resource::<Focus>().is_focused("top"));
+
+        // Explicit neighbours take priority
+        navigate(&mut app, FocusNavigation::Move(FocusDirection::Up));
+        assert!(app.world().resource::<Focus>().is_focused("bottom"));
+    }
+}
@@ -19,9 +19,9 @@ impl<S: IntoBundle<B = Style>> MavericNode for ImageNode<S> {
         commands.scope(|commands| {
             commands.map_node(|x| &x.path).advanced(|args, commands| {
                 let path = args.node;
-                let server: &AssetServer = commands
-                    .get_res_untracked()
-                    .expect("Could not get asset server");
+                let Some(server) = commands.get_res_or_report::<AssetServer>() else {
+                    return;
+                };
                 let texture = server.load(*path);
                 let bundle = UiImage {
                     texture,
@@ -1,11 +1,31 @@
 #[cfg(feature = "bevy_ui")]
+pub mod button_handlers;
+#[cfg(feature = "bevy_ui")]
 pub mod button_node;
 #[cfg(feature = "bevy_ui")]
+pub mod button_styles;
+#[cfg(feature = "bevy_ui")]
 pub mod carousel;
 #[cfg(feature = "bevy_ui")]
+pub mod focus;
+#[cfg(feature = "bevy_ui")]
 pub mod image_node;
 #[cfg(feature = "bevy_ui")]
+pub mod modal_stack;
+#[cfg(feature = "bevy_ui")]
+pub mod scroll_view_node;
+#[cfg(feature = "bevy_ui")]
+pub mod text_input_node;
+#[cfg(feature = "bevy_ui")]
 pub mod text_node;
+#[cfg(feature = "bevy_ui")]
+pub mod toast_stack;
+#[cfg(feature = "bevy_ui")]
+pub mod tooltip;
+#[cfg(feature = "bevy_ui")]
+pub mod virtual_list_node;
+#[cfg(feature = "bevy_ui")]
+pub mod widgets_plugin;
 
 #[cfg(feature = "bevy_text")]
 pub mod multi_text2d_node;
@@ -14,17 +34,37 @@ pub mod text2d_node;
 
 pub mod sprite_node;
 pub mod prelude {
+    #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::button_handlers::*;
     #[cfg(feature = "bevy_ui")]
     pub use crate::widgets::button_node::*;
     #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::button_styles::*;
+    #[cfg(feature = "bevy_ui")]
     pub use crate::widgets::carousel::*;
     #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::focus::*;
+    #[cfg(feature = "bevy_ui")]
     pub use crate::widgets::image_node::*;
+    #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::modal_stack::*;
     #[cfg(feature = "bevy_text")]
     pub use crate::widgets::multi_text2d_node::*;
+    #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::scroll_view_node::*;
     pub use crate::widgets::sprite_node::*;
     #[cfg(feature = "bevy_text")]
     pub use crate::widgets::text2d_node::*;
     #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::text_input_node::*;
+    #[cfg(feature = "bevy_ui")]
     pub use crate::widgets::text_node::*;
+    #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::toast_stack::*;
+    #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::tooltip::*;
+    #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::virtual_list_node::*;
+    #[cfg(feature = "bevy_ui")]
+    pub use crate::widgets::widgets_plugin::*;
 }
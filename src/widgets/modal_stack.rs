@@ -0,0 +1,440 @@
+use crate::prelude::*;
+use bevy::ui::{FocusPolicy, UiSystem};
+use std::{marker::PhantomData, time::Duration};
+
+/// Modals are drawn above everything with a lower global z-index
+const MODAL_Z_INDEX: i32 = 1000;
+/// How small dialogs are when they appear and disappear
+const HIDDEN_SCALE: f32 = 0.9;
+
+#[derive(Debug, Default)]
+pub struct ModalPlugin;
+
+impl Plugin for ModalPlugin {
+    fn build(&self, app: &mut App) {
+        // Dialogs trap focus, which needs the focus plugin
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.add_systems(PreUpdate, close_modal_on_escape.after(UiSystem::Focus));
+    }
+}
+
+/// A description of a dialog shown by a `ModalStack`
+pub trait ModalDialog: PartialEq + Clone + Send + Sync + 'static {
+    type Node: for<'w, 's> MavericNode<Context<'w, 's> = ()>;
+
+    fn to_node(&self) -> Self::Node;
+}
+
+/// The open dialogs, from bottom to top
+#[derive(Debug, Clone, PartialEq, Eq, Resource)]
+pub struct ModalDialogs<D: ModalDialog> {
+    dialogs: Vec<(u32, D)>,
+    next_id: u32,
+}
+
+impl<D: ModalDialog> Default for ModalDialogs<D> {
+    fn default() -> Self {
+        Self {
+            dialogs: vec![],
+            next_id: 0,
+        }
+    }
+}
+
+impl<D: ModalDialog> ModalDialogs<D> {
+    /// Open a dialog on top of the others. Returns its id
+    pub fn push(&mut self, dialog: D) -> u32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.dialogs.push((id, dialog));
+        id
+    }
+
+    /// Close the top dialog
+    pub fn pop(&mut self) -> Option<D> {
+        self.dialogs.pop().map(|(_, dialog)| dialog)
+    }
+
+    /// Close the dialog with this id
+    pub fn close(&mut self, id: u32) -> Option<D> {
+        let index = self.dialogs.iter().position(|(i, _)| *i == id)?;
+        Some(self.dialogs.remove(index).1)
+    }
+
+    #[must_use]
+    pub fn top(&self) -> Option<&D> {
+        self.dialogs.last().map(|(_, dialog)| dialog)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &D> {
+        self.dialogs.iter().map(|(_, dialog)| dialog)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.dialogs.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.dialogs.is_empty()
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn close_dialog<D: ModalDialog>(dialogs: &mut ModalDialogs<D>, id: &u32) {
+    dialogs.close(*id);
+}
+
+/// Shows the dialogs in `ModalDialogs<D>` above everything else, each over a backdrop which blocks interaction beneath it.
+///
+/// While a dialog is open, focus can only move between the `Focusable` entities inside the top dialog.
+/// Dialogs scale and fade in and out so you must register the `TransformScaleLens` and `BackgroundColorLens` transitions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalStack<D: ModalDialog> {
+    pub backdrop_color: Color,
+    /// How long dialogs take to appear and disappear
+    pub transition_duration: Duration,
+    pub close_on_escape: bool,
+    pub close_on_backdrop_click: bool,
+    phantom: PhantomData<D>,
+}
+
+impl<D: ModalDialog> ModalStack<D> {
+    #[must_use]
+    pub const fn new(backdrop_color: Color, transition_duration: Duration) -> Self {
+        Self {
+            backdrop_color,
+            transition_duration,
+            close_on_escape: true,
+            close_on_backdrop_click: true,
+            phantom: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_close_on_backdrop_click(mut self, close_on_backdrop_click: bool) -> Self {
+        self.close_on_backdrop_click = close_on_backdrop_click;
+        self
+    }
+}
+
+impl<D: ModalDialog> MavericNode for ModalStack<D> {
+    type Context<'w, 's> = Res<'w, ModalDialogs<D>>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.ignore_node().ignore_context().insert(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ..default()
+        });
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, dialogs, mut commands)) =
+            commands.unordered_children_with_node_and_context()
+        else {
+            return;
+        };
+
+        let hidden_color = node.backdrop_color.with_alpha(0.0);
+        let duration = node.transition_duration;
+
+        for (depth, (id, dialog)) in dialogs.dialogs.iter().enumerate() {
+            let z_index = MODAL_Z_INDEX + 2 * depth as i32;
+            let close = ButtonHandler::update_resource_with(*id, close_dialog::<D>);
+
+            let backdrop = ModalBackdrop {
+                z_index,
+                on_close: node.close_on_backdrop_click.then(|| close.clone()),
+            }
+            .with_transition_in_out::<BackgroundColorLens>(
+                hidden_color,
+                node.backdrop_color,
+                hidden_color,
+                duration,
+                duration,
+                Some(Ease::CubicOut),
+                Some(Ease::CubicIn),
+            );
+            commands.add_child(id.wrapping_mul(2), backdrop, &());
+
+            let panel = ModalPanel {
+                depth,
+                z_index: z_index + 1,
+                on_close: node.close_on_escape.then_some(close),
+                dialog: dialog.clone(),
+            }
+            .with_transition_in_out::<TransformScaleLens>(
+                Vec3::splat(HIDDEN_SCALE),
+                Vec3::ONE,
+                Vec3::splat(HIDDEN_SCALE),
+                duration,
+                duration,
+                Some(Ease::BackOut),
+                Some(Ease::CubicIn),
+            );
+            commands.add_child(id.wrapping_mul(2) + 1, panel, &());
+        }
+    }
+}
+
+/// Covers the screen beneath a dialog
+#[derive(Debug, Clone, PartialEq)]
+struct ModalBackdrop {
+    z_index: i32,
+    on_close: Option<ButtonHandler>,
+}
+
+impl MavericNode for ModalBackdrop {
+    type Context<'w, 's> = ();
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.insert_static_bundle((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+        ));
+        commands.advanced(|args, commands| {
+            if !args.is_hot() {
+                return;
+            }
+            commands.insert((
+                ZIndex::Global(args.node.z_index),
+                ButtonHandlers {
+                    on_press: args.node.on_close.clone(),
+                    ..default()
+                },
+            ));
+        });
+    }
+
+    fn set_children<R: MavericRoot>(
+        _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+    ) {
+    }
+}
+
+/// Holds a dialog and blocks interaction with the backdrop behind it
+#[derive(Debug, Clone, PartialEq)]
+struct ModalPanel<D: ModalDialog> {
+    depth: usize,
+    z_index: i32,
+    on_close: Option<ButtonHandler>,
+    dialog: D,
+}
+
+impl<D: ModalDialog> MavericNode for ModalPanel<D> {
+    type Context<'w, 's> = ();
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        let mut commands = commands.ignore_context();
+        commands.insert_static_bundle((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+        ));
+        commands.advanced(|args, commands| {
+            if !args.is_hot() {
+                return;
+            }
+            commands.insert((
+                ZIndex::Global(args.node.z_index),
+                ModalLayer {
+                    depth: args.node.depth,
+                    on_close: args.node.on_close.clone(),
+                },
+            ));
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, context, mut commands)) =
+            commands.unordered_children_with_node_and_context()
+        else {
+            return;
+        };
+        commands.add_child(0, node.dialog.to_node(), context);
+    }
+}
+
+/// An open dialog. Focus is trapped inside the one with the greatest depth
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct ModalLayer {
+    pub depth: usize,
+    /// What to do when escape is pressed
+    pub on_close: Option<ButtonHandler>,
+}
+
+pub(crate) type ModalLayerQuery<'w, 's> =
+    Query<'w, 's, (Entity, &'static ModalLayer), Without<ScheduledForDeletion>>;
+
+/// The top open dialog, if any
+pub(crate) fn top_modal_layer<'a>(layers: &'a ModalLayerQuery) -> Option<(Entity, &'a ModalLayer)> {
+    layers.iter().max_by_key(|(_, layer)| layer.depth)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn close_modal_on_escape(
+    mut commands: Commands,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    layers: ModalLayerQuery,
+    text_inputs: Query<&TextInputState>,
+) {
+    if !keys.is_some_and(|keys| keys.just_pressed(KeyCode::Escape)) {
+        return;
+    }
+    // Escape stops editing instead
+    if text_inputs.iter().any(|state| state.active) {
+        return;
+    }
+    if let Some(handler) = top_modal_layer(&layers).and_then(|(_, layer)| layer.on_close.as_ref()) {
+        handler.run(&mut commands);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::TimePlugin;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Confirm(&'static str);
+
+    impl ModalDialog for Confirm {
+        type Node = Label;
+
+        fn to_node(&self) -> Self::Node {
+            Label(self.0)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label(&'static str);
+
+    impl MavericNode for Label {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, ModalDialogs<Confirm>>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(
+                0,
+                ModalStack::<Confirm>::new(Color::BLACK, Duration::from_secs(1)),
+                context,
+            );
+        }
+    }
+
+    fn open_dialogs(app: &mut App) -> Vec<&'static str> {
+        let world = app.world_mut();
+        let mut layers: Vec<_> = world
+            .query_filtered::<(&ModalLayer, &Children), Without<ScheduledForDeletion>>()
+            .iter(world)
+            .map(|(layer, children)| (layer.depth, children[0]))
+            .collect();
+        layers.sort_by_key(|(depth, _)| *depth);
+        layers
+            .into_iter()
+            .map(|(_, child)| {
+                world
+                    .get::<MavericNodeComponent<Label>>(child)
+                    .unwrap()
+                    .node
+                    .0
+            })
+            .collect()
+    }
+
+    #[test]
+    pub fn test_modal_stack() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.init_resource::<ModalDialogs<Confirm>>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(open_dialogs(&mut app), Vec::<&str>::new());
+
+        let mut dialogs = app.world_mut().resource_mut::<ModalDialogs<Confirm>>();
+        dialogs.push(Confirm("quit"));
+        dialogs.push(Confirm("really quit"));
+        app.update();
+        assert_eq!(open_dialogs(&mut app), vec!["quit", "really quit"]);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.update();
+        assert_eq!(
+            app.world().resource::<ModalDialogs<Confirm>>().top(),
+            Some(&Confirm("quit"))
+        );
+        assert_eq!(open_dialogs(&mut app), vec!["quit"]);
+        let world = app.world_mut();
+        assert_eq!(
+            world
+                .query_filtered::<(), (With<ModalLayer>, With<ScheduledForDeletion>)>()
+                .iter(world)
+                .count(),
+            1,
+            "Closed dialogs should linger while they animate out"
+        );
+
+        // Clicking the remaining backdrop closes the remaining dialog
+        let world = app.world_mut();
+        let mut backdrops =
+            world.query_filtered::<&mut Interaction, (With<ButtonHandlers>, Without<ScheduledForDeletion>)>();
+        *backdrops.single_mut(world) = Interaction::Pressed;
+        app.update();
+        app.update();
+        assert!(app.world().resource::<ModalDialogs<Confirm>>().is_empty());
+    }
+}
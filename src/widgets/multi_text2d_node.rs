@@ -49,7 +49,11 @@ impl<const SECTIONS: usize, T: core::fmt::Display + PartialEq + Clone + Send + S
     type Context<'w, 's> = ();
 
     fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
-        commands.insert_static_bundle((SpatialBundle::default(), TextLayoutInfo::default(), SpriteSource::default()));
+        commands.insert_static_bundle((
+            SpatialBundle::default(),
+            TextLayoutInfo::default(),
+            SpriteSource::default(),
+        ));
         commands.node_to_bundle(|x| &x.text_anchor);
         commands.node_to_component(|x| &x.text_2d_bounds, |l, r| text_2d_bound_compare(*l, *r));
 
@@ -58,15 +62,15 @@ impl<const SECTIONS: usize, T: core::fmt::Display + PartialEq + Clone + Send + S
                 .advanced(|args, commands| {
                     if args.is_hot() {
                         let node = args.node;
-                        let server: &AssetServer = commands
-                            .get_res_untracked()
-                            .expect("Could not get asset server");
+                        let server = commands.get_res_or_report::<AssetServer>();
 
                         let mut bundle = Text::default().with_justify(node.justify_text);
                         bundle.linebreak_behavior = node.linebreak_behavior;
 
                         for section in node.sections.iter().flatten() {
-                            let font = server.load(section.font);
+                            let font = server
+                                .map(|server| server.load(section.font))
+                                .unwrap_or_default();
 
                             let style = TextStyle {
                                 font,
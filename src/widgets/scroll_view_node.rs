@@ -0,0 +1,830 @@
+use crate::prelude::*;
+use crate::transition::speed::ScalarSpeed;
+use crate::transition::ui_lenses::{StyleLeftLens, StyleTopLens};
+use bevy::{
+    ecs::event::ManualEventReader,
+    input::{
+        gamepad::{GamepadAxis, GamepadAxisType},
+        mouse::{MouseScrollUnit, MouseWheel},
+    },
+    ui::UiSystem,
+};
+
+/// Scrolling slower than this (in logical pixels per second) comes to a stop
+const MIN_VELOCITY: f32 = 20.0;
+const GAMEPAD_DEAD_ZONE: f32 = 0.1;
+
+#[derive(Debug, Default)]
+pub struct ScrollViewPlugin;
+
+impl Plugin for ScrollViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            (
+                scroll_with_input,
+                apply_scroll_physics,
+                position_scroll_content,
+            )
+                .chain()
+                .after(UiSystem::Focus),
+        );
+    }
+}
+
+pub(crate) fn register_scroll_to<R: MavericRoot>(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        resolve_scroll_to::<R>
+            .after(UiSystem::Focus)
+            .before(apply_scroll_physics),
+    );
+}
+
+/// A view which clips its children and scrolls them with the mouse wheel, dragging or the right gamepad stick.
+///
+/// The content is moved with transitions so you must register the `StyleTopLens` and `StyleLeftLens` transitions.
+/// Read or change the position with the `ScrollState` component, or insert `ScrollTo` to scroll to a child
+#[derive(PartialEq, Debug, Clone)]
+pub struct ScrollViewNode<S: IntoBundle<B = Style>, C: ChildTuple + Clone> {
+    pub style: S,
+    pub direction: ScrollDirection,
+    pub physics: ScrollPhysics,
+    pub snap: ScrollSnap,
+    /// Scroll so that the child with this key is at the start of the view whenever this changes
+    pub scroll_to: Option<ChildKey>,
+    pub children: C,
+}
+
+impl<S: IntoBundle<B = Style>, C: ChildTuple + Clone> MavericNode for ScrollViewNode<S, C> {
+    type Context<'w, 's> = C::Context<'w, 's>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        let mut commands = commands.ignore_context();
+        commands.insert_static_bundle((
+            NodeBundle::default(),
+            Interaction::default(),
+            ScrollState::default(),
+        ));
+
+        commands.scope(|commands| {
+            commands
+                .advanced(|args, commands| {
+                    if !args.is_hot() {
+                        return;
+                    }
+                    let node = args.node;
+                    let mut style = node.style.clone().into_bundle();
+                    style.overflow = Overflow::clip();
+                    commands.insert((
+                        style,
+                        ScrollView {
+                            direction: node.direction,
+                            physics: node.physics,
+                            snap: node.snap,
+                        },
+                    ));
+
+                    let previous = args.previous.and_then(|p| p.scroll_to);
+                    if let Some(key) = node.scroll_to.filter(|key| previous != Some(*key)) {
+                        commands.insert(ScrollTo(key));
+                    }
+                })
+                .finish();
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, context, mut commands)) =
+            commands.unordered_children_with_node_and_context()
+        else {
+            return;
+        };
+        commands.add_child(
+            0,
+            ScrollContentNode {
+                direction: node.direction,
+                children: node.children.clone(),
+            },
+            context,
+        );
+    }
+}
+
+/// The node which holds the children of a scroll view and is moved to scroll them
+#[derive(PartialEq, Debug, Clone)]
+struct ScrollContentNode<C: ChildTuple> {
+    direction: ScrollDirection,
+    children: C,
+}
+
+impl<C: ChildTuple> MavericNode for ScrollContentNode<C> {
+    type Context<'w, 's> = C::Context<'w, 's>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        let mut commands = commands.ignore_context();
+        commands.insert_static_bundle((NodeBundle::default(), ScrollContent));
+
+        commands.scope(|commands| {
+            commands
+                .advanced(|args, commands| {
+                    if !args.is_hot() {
+                        return;
+                    }
+                    // The position is controlled by the scroll view
+                    let (top, left) = commands
+                        .get::<Style>()
+                        .map_or((Val::Px(0.0), Val::Px(0.0)), |s| (s.top, s.left));
+
+                    let mut style = Style {
+                        position_type: PositionType::Absolute,
+                        top,
+                        left,
+                        ..default()
+                    };
+                    match args.node.direction {
+                        ScrollDirection::Vertical => {
+                            style.width = Val::Percent(100.0);
+                            style.flex_direction = FlexDirection::Column;
+                        }
+                        ScrollDirection::Horizontal => {
+                            style.height = Val::Percent(100.0);
+                            style.flex_direction = FlexDirection::Row;
+                        }
+                        ScrollDirection::Both => {}
+                    }
+                    commands.insert(style);
+                })
+                .finish();
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        commands.map_args(|x| &x.children).add_children();
+    }
+}
+
+/// The axes a scroll view can scroll along
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollDirection {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl ScrollDirection {
+    /// One for each axis that can be scrolled and zero otherwise
+    #[must_use]
+    pub const fn axes(&self) -> Vec2 {
+        match self {
+            Self::Vertical => Vec2::Y,
+            Self::Horizontal => Vec2::X,
+            Self::Both => Vec2::ONE,
+        }
+    }
+}
+
+/// How a scroll view responds to input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollPhysics {
+    /// How quickly scrolling slows down after the wheel is turned or a drag is released. Higher values stop sooner
+    pub friction: f32,
+    /// How far one line of the mouse wheel scrolls, in logical pixels
+    pub line_height: f32,
+    /// How fast a fully tilted gamepad stick scrolls, in logical pixels per second
+    pub gamepad_speed: f32,
+    /// How fast the content moves towards the scroll position
+    pub speed: ScalarSpeed,
+}
+
+impl Default for ScrollPhysics {
+    fn default() -> Self {
+        Self {
+            friction: 8.0,
+            line_height: 40.0,
+            gamepad_speed: 800.0,
+            speed: ScalarSpeed::new(4000.0),
+        }
+    }
+}
+
+/// Where a scroll view comes to rest
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ScrollSnap {
+    /// Anywhere
+    #[default]
+    None,
+    /// At multiples of this many logical pixels
+    Interval(f32),
+    /// At the start of one of its children
+    Children,
+}
+
+impl ScrollSnap {
+    /// The snap point nearest to `offset`
+    fn nearest(self, offset: Vec2, children: impl Iterator<Item = Vec2>) -> Option<Vec2> {
+        match self {
+            Self::Interval(interval) if interval > 0.0 => {
+                Some((offset / interval).round() * interval)
+            }
+            Self::Children => children.min_by(|a, b| {
+                a.distance_squared(offset)
+                    .total_cmp(&b.distance_squared(offset))
+            }),
+            Self::None | Self::Interval(_) => None,
+        }
+    }
+}
+
+/// The settings of a scroll view. This is inserted by `ScrollViewNode`
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct ScrollView {
+    pub direction: ScrollDirection,
+    pub physics: ScrollPhysics,
+    pub snap: ScrollSnap,
+}
+
+/// Marks the node that holds the children of a scroll view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+pub struct ScrollContent;
+
+/// Insert this on a scroll view to scroll to the child with this key once it has been laid out.
+///
+/// The child can be nested anywhere inside the content; the one nearest to the content is used.
+/// If there is no ui node with that key the request is removed and a `MavericError` is sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct ScrollTo(pub ChildKey);
+
+/// The scroll position of a scroll view, in logical pixels
+#[derive(Debug, Clone, Copy, PartialEq, Default, Component)]
+pub struct ScrollState {
+    /// How far the content is scrolled from the start
+    pub offset: Vec2,
+    /// How fast the offset is changing, per second
+    pub velocity: Vec2,
+    /// Where the cursor was last frame, if the content is being dragged
+    drag_position: Option<Vec2>,
+}
+
+impl ScrollState {
+    /// Jump to a position, stopping any movement
+    pub const fn scroll_to_offset(&mut self, offset: Vec2) {
+        self.offset = offset;
+        self.velocity = Vec2::ZERO;
+    }
+
+    #[must_use]
+    pub const fn is_dragging(&self) -> bool {
+        self.drag_position.is_some()
+    }
+
+    /// Move by the velocity, slow down, come to rest at a snap point, and stay within `max_offset`
+    fn advance(
+        mut self,
+        view: &ScrollView,
+        max_offset: Vec2,
+        snap_points: impl Iterator<Item = Vec2>,
+        delta_seconds: f32,
+    ) -> Self {
+        let axes = view.direction.axes();
+        if !self.is_dragging() {
+            self.offset += self.velocity * delta_seconds;
+            self.velocity *= (-view.physics.friction * delta_seconds).exp();
+
+            if self.velocity.length() < MIN_VELOCITY {
+                self.velocity = Vec2::ZERO;
+                if let Some(point) = view.snap.nearest(self.offset, snap_points) {
+                    self.offset = point;
+                }
+            }
+        }
+
+        let clamped = (self.offset * axes).clamp(Vec2::ZERO, max_offset * axes);
+        // Stop when reaching either end
+        self.velocity = Vec2::select(clamped.cmpeq(self.offset), self.velocity, Vec2::ZERO);
+        self.offset = clamped;
+        self
+    }
+}
+
+/// The position of the top left of `item` relative to the top left of `content`
+fn offset_within(content: (&Node, &GlobalTransform), item: (&Node, &GlobalTransform)) -> Vec2 {
+    let top_left = |(node, transform): (&Node, &GlobalTransform)| {
+        transform.translation().truncate() - node.size() * 0.5
+    };
+    top_left(item) - top_left(content)
+}
+
+type ContentQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Node,
+        &'static GlobalTransform,
+        Option<&'static Children>,
+    ),
+    With<ScrollContent>,
+>;
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
+fn scroll_with_input(
+    time: Res<Time>,
+    // This is only registered if the app has the input plugin
+    wheel_events: Option<Res<Events<MouseWheel>>>,
+    mut wheel_reader: Local<ManualEventReader<MouseWheel>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    windows: Query<&Window>,
+    gamepads: Option<Res<Gamepads>>,
+    gamepad_axes: Option<Res<Axis<GamepadAxis>>>,
+    mut views: Query<(
+        &ScrollView,
+        &mut ScrollState,
+        Option<&Interaction>,
+        &ViewVisibility,
+    )>,
+) {
+    let (mut lines, mut pixels) = (Vec2::ZERO, Vec2::ZERO);
+    if let Some(wheel_events) = &wheel_events {
+        for event in wheel_reader.read(wheel_events) {
+            match event.unit {
+                MouseScrollUnit::Line => lines += Vec2::new(event.x, event.y),
+                MouseScrollUnit::Pixel => pixels += Vec2::new(event.x, event.y),
+            }
+        }
+    }
+
+    let cursor = windows.iter().find_map(Window::cursor_position);
+    let mouse_pressed = mouse.is_some_and(|m| m.pressed(MouseButton::Left));
+
+    let stick = gamepads
+        .zip(gamepad_axes)
+        .and_then(|(gamepads, axes)| {
+            gamepads.iter().find_map(|gamepad| {
+                let axis = |axis_type| axes.get(GamepadAxis::new(gamepad, axis_type));
+                let stick = Vec2::new(
+                    axis(GamepadAxisType::RightStickX)?,
+                    axis(GamepadAxisType::RightStickY)?,
+                );
+                (stick.length() > GAMEPAD_DEAD_ZONE).then_some(stick)
+            })
+        })
+        // The stick points up to scroll towards the start
+        .map(|stick| stick * Vec2::new(1.0, -1.0));
+
+    let delta_seconds = time.delta_seconds();
+
+    for (view, mut state, interaction, visibility) in &mut views {
+        let axes = view.direction.axes();
+        let hovered = interaction.is_some_and(|i| *i != Interaction::None);
+        let mut new_state = *state;
+
+        if hovered {
+            let mut wheel = lines * view.physics.line_height + pixels;
+            if view.direction == ScrollDirection::Horizontal {
+                // Most mice only have a vertical wheel
+                wheel = Vec2::new(wheel.x + wheel.y, 0.0);
+            }
+            // An impulse that comes to rest after travelling the wheel distance
+            new_state.velocity -= wheel * axes * view.physics.friction;
+        }
+
+        match (new_state.drag_position, cursor) {
+            (Some(previous), Some(cursor)) if mouse_pressed => {
+                let moved = (cursor - previous) * axes;
+                new_state.offset -= moved;
+                if delta_seconds > 0.0 {
+                    new_state.velocity = -moved / delta_seconds;
+                }
+                new_state.drag_position = Some(cursor);
+            }
+            (None, Some(cursor)) if mouse_pressed && interaction == Some(&Interaction::Pressed) => {
+                new_state.velocity = Vec2::ZERO;
+                new_state.drag_position = Some(cursor);
+            }
+            (Some(_), _) => new_state.drag_position = None,
+            _ => {}
+        }
+
+        if let Some(stick) = stick.filter(|_| visibility.get()) {
+            new_state.velocity = stick * axes * view.physics.gamepad_speed;
+        }
+
+        state.set_if_neq(new_state);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn apply_scroll_physics(
+    time: Res<Time>,
+    mut views: Query<(&ScrollView, &mut ScrollState, &Node, &Children)>,
+    contents: ContentQuery,
+    items: Query<(&Node, &GlobalTransform)>,
+) {
+    for (view, mut state, node, children) in &mut views {
+        let Some((content_node, content_transform, content_children)) =
+            children.iter().find_map(|c| contents.get(*c).ok())
+        else {
+            continue;
+        };
+
+        let max_offset = (content_node.size() - node.size()).max(Vec2::ZERO);
+        let snap_points = content_children
+            .into_iter()
+            .flatten()
+            .filter_map(|child| items.get(*child).ok())
+            .map(|item| offset_within((content_node, content_transform), item));
+
+        let new_state = state.advance(view, max_offset, snap_points, time.delta_seconds());
+        state.set_if_neq(new_state);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn position_scroll_content(
+    mut commands: Commands,
+    views: Query<(&ScrollView, &ScrollState, &Children), Changed<ScrollState>>,
+    contents: Query<(), With<ScrollContent>>,
+    world: &World,
+) {
+    for (view, state, children) in &views {
+        let Some(content) = children.iter().find(|c| contents.contains(**c)) else {
+            continue;
+        };
+        let Some(mut ec) = commands.get_entity(*content) else {
+            continue;
+        };
+        let mut commands = ComponentCommands::new(&mut ec, world, SetEvent::Updated);
+        let axes = view.direction.axes();
+        let speed = view.physics.speed;
+        if axes.y > 0.0 {
+            commands.transition_value::<StyleTopLens>(Val::Px(-state.offset.y), speed, None);
+        }
+        if axes.x > 0.0 {
+            commands.transition_value::<StyleLeftLens>(Val::Px(-state.offset.x), speed, None);
+        }
+    }
+}
+
+type ScrollToQuery<'w, 's, R> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static ScrollTo,
+        &'static mut ScrollState,
+        &'static Children,
+    ),
+    With<MavericChildComponent<R>>,
+>;
+
+#[allow(clippy::needless_pass_by_value)]
+fn resolve_scroll_to<R: MavericRoot>(
+    mut commands: Commands,
+    mut views: ScrollToQuery<R>,
+    contents: Query<(Entity, &Node, &GlobalTransform), With<ScrollContent>>,
+    hierarchy: Query<&Children>,
+    keys: Query<&MavericChildComponent<R>>,
+    items: Query<(&Node, &GlobalTransform)>,
+) {
+    for (entity, scroll_to, mut state, children) in &mut views {
+        let Some((content, content_node, content_transform)) =
+            children.iter().find_map(|c| contents.get(*c).ok())
+        else {
+            continue;
+        };
+
+        let Some((node, transform)) = find_descendant(content, scroll_to.0, &hierarchy, &keys)
+            .and_then(|item| items.get(item).ok())
+        else {
+            commands.entity(entity).remove::<ScrollTo>();
+            crate::maveric_error::send_error(
+                &mut commands,
+                MavericError {
+                    entity,
+                    kind: MavericErrorKind::Custom(format!(
+                        "Could not scroll to {} as the scroll view has no ui node with that key",
+                        scroll_to.0
+                    )),
+                },
+            );
+            continue;
+        };
+
+        // Wait until the child has been laid out
+        if node.size() == Vec2::ZERO {
+            continue;
+        }
+
+        state.scroll_to_offset(offset_within(
+            (content_node, content_transform),
+            (node, transform),
+        ));
+        commands.entity(entity).remove::<ScrollTo>();
+    }
+}
+
+/// The shallowest descendant of `entity` with this key
+fn find_descendant<R: MavericRoot>(
+    entity: Entity,
+    key: ChildKey,
+    hierarchy: &Query<&Children>,
+    keys: &Query<&MavericChildComponent<R>>,
+) -> Option<Entity> {
+    let mut level: Vec<Entity> = hierarchy.get(entity).map_or(vec![], |c| c.to_vec());
+    while !level.is_empty() {
+        if let Some(found) = level
+            .iter()
+            .find(|e| keys.get(**e).is_ok_and(|child| child.key == key))
+        {
+            return Some(*found);
+        }
+        level = level
+            .iter()
+            .filter_map(|e| hierarchy.get(*e).ok())
+            .flatten()
+            .copied()
+            .collect();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::reflect::{GetField, ReflectMut};
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use std::time::Duration;
+
+    fn view(snap: ScrollSnap) -> ScrollView {
+        ScrollView {
+            direction: ScrollDirection::Vertical,
+            physics: ScrollPhysics::default(),
+            snap,
+        }
+    }
+
+    fn settle(view: &ScrollView, mut state: ScrollState, snap_points: &[Vec2]) -> ScrollState {
+        for _ in 0..1000 {
+            state = state.advance(
+                view,
+                Vec2::new(0.0, 500.0),
+                snap_points.iter().copied(),
+                1.0 / 60.0,
+            );
+        }
+        state
+    }
+
+    #[test]
+    pub fn test_inertia() {
+        let view = view(ScrollSnap::None);
+        let state = ScrollState {
+            velocity: Vec2::new(100.0, 100.0 * view.physics.friction),
+            ..default()
+        };
+
+        let state = settle(&view, state, &[]);
+        assert_eq!(state.velocity, Vec2::ZERO);
+        assert!(
+            state.offset.x.abs() < f32::EPSILON,
+            "Vertical views should not scroll horizontally"
+        );
+        assert!(
+            (state.offset.y - 100.0).abs() < 5.0,
+            "An impulse should travel the wheel distance but went {}",
+            state.offset.y
+        );
+
+        let state = ScrollState {
+            velocity: Vec2::new(0.0, -100.0),
+            ..state
+        };
+        let state = state.advance(&view, Vec2::new(0.0, 500.0), std::iter::empty(), 10.0);
+        assert_eq!(
+            state.offset,
+            Vec2::ZERO,
+            "Scrolling should stop at the start"
+        );
+        assert_eq!(state.velocity, Vec2::ZERO);
+    }
+
+    #[test]
+    pub fn test_snap() {
+        let state = ScrollState {
+            offset: Vec2::new(0.0, 130.0),
+            ..default()
+        };
+
+        let snapped = settle(&view(ScrollSnap::Interval(50.0)), state, &[]);
+        assert_eq!(snapped.offset, Vec2::new(0.0, 150.0));
+
+        let children = [Vec2::ZERO, Vec2::new(0.0, 120.0), Vec2::new(0.0, 240.0)];
+        let snapped = settle(&view(ScrollSnap::Children), state, &children);
+        assert_eq!(snapped.offset, Vec2::new(0.0, 120.0));
+
+        let dragging = ScrollState {
+            drag_position: Some(Vec2::ZERO),
+            ..state
+        };
+        let still_dragging = settle(&view(ScrollSnap::Children), dragging, &children);
+        assert_eq!(
+            still_dragging.offset, state.offset,
+            "Views being dragged should not snap"
+        );
+    }
+
+    #[derive(Debug, Default, Resource)]
+    struct Model {
+        scroll_to: Option<ChildKey>,
+    }
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Model>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(
+                0,
+                ScrollViewNode {
+                    style: Style::default(),
+                    direction: ScrollDirection::Vertical,
+                    physics: ScrollPhysics::default(),
+                    snap: ScrollSnap::None,
+                    scroll_to: context.scroll_to,
+                    children: (Items(10),),
+                },
+                &(),
+            );
+        }
+    }
+
+    /// A node with `self.0` items, so the items are not direct children of the scroll content
+    #[derive(Debug, Clone, PartialEq)]
+    struct Items(u32);
+
+    impl MavericNode for Items {
+        type Context<'w, 's> = ();
+
+        fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+            commands
+                .ignore_context()
+                .insert_static_bundle(NodeBundle::default());
+        }
+
+        fn set_children<R: MavericRoot>(
+            commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+            let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+                return;
+            };
+            for index in 0..node.0 {
+                commands.add_child(index, Item, &());
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Item;
+
+    impl MavericNode for Item {
+        type Context<'w, 's> = ();
+
+        fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+            commands
+                .ignore_context()
+                .insert_static_bundle(NodeBundle::default());
+        }
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.register_transition::<StyleTopLens>();
+        app.register_transition::<StyleLeftLens>();
+        app
+    }
+
+    /// Do what the ui layout would: give the node a size and put its top left at `top_left`
+    fn lay_out(app: &mut App, entity: Entity, size: Vec2, top_left: Vec2) {
+        let world = app.world_mut();
+        let mut node = world.get_mut::<Node>(entity).unwrap();
+        let ReflectMut::Struct(node) = node.reflect_mut() else {
+            unreachable!()
+        };
+        *node.get_field_mut::<Vec2>("calculated_size").unwrap() = size;
+        *world.get_mut::<GlobalTransform>(entity).unwrap() =
+            GlobalTransform::from_translation((top_left + size * 0.5).extend(0.0));
+    }
+
+    /// Lay out a 100 by 200 view of ten 100 by 100 items
+    fn lay_out_list(app: &mut App) -> Entity {
+        let world = app.world_mut();
+        let view = world
+            .query_filtered::<Entity, With<ScrollView>>()
+            .single(world);
+        let content = world
+            .query_filtered::<Entity, With<ScrollContent>>()
+            .single(world);
+        let items: Vec<(Entity, u32)> = world
+            .query_filtered::<(Entity, &MavericChildComponent<Root>), With<MavericNodeComponent<Item>>>()
+            .iter(world)
+            .filter_map(|(entity, child)| match child.key {
+                ChildKey::Number(NumberKey::Unsigned(index)) => Some((entity, index)),
+                _ => None,
+            })
+            .collect();
+
+        lay_out(app, view, Vec2::new(100.0, 200.0), Vec2::ZERO);
+        lay_out(app, content, Vec2::new(100.0, 1000.0), Vec2::ZERO);
+        for (entity, index) in items {
+            #[allow(clippy::cast_precision_loss)]
+            let top = index as f32 * 100.0;
+            lay_out(app, entity, Vec2::splat(100.0), Vec2::new(0.0, top));
+        }
+        view
+    }
+
+    fn scroll_state(app: &mut App) -> ScrollState {
+        let world = app.world_mut();
+        *world.query::<&ScrollState>().single(world)
+    }
+
+    #[test]
+    pub fn test_scroll_to() {
+        let mut app = app();
+        app.insert_resource(Model {
+            scroll_to: Some(5_u32.into()),
+        });
+        app.register_maveric::<Root>();
+        app.update();
+
+        // Nothing has been laid out yet so the request waits
+        let view = lay_out_list(&mut app);
+        assert!(app.world().get::<ScrollTo>(view).is_some());
+
+        app.update();
+        assert_eq!(scroll_state(&mut app).offset, Vec2::new(0.0, 500.0));
+        assert!(app.world().get::<ScrollTo>(view).is_none());
+
+        // There is no item with this key
+        app.world_mut().resource_mut::<Model>().scroll_to = Some(99_u32.into());
+        app.update();
+        assert!(app.world().get::<ScrollTo>(view).is_none());
+        assert_eq!(scroll_state(&mut app).offset, Vec2::new(0.0, 500.0));
+        let events = app.world().resource::<Events<MavericError>>();
+        let errors: Vec<_> = events.get_reader().read(events).cloned().collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].entity, view);
+    }
+
+    #[test]
+    pub fn test_wheel_scrolling() {
+        let mut app = app();
+        // This is normally registered by the input plugin
+        app.add_event::<MouseWheel>();
+        app.init_resource::<Model>();
+        app.register_maveric::<Root>();
+        app.update();
+        let view = lay_out_list(&mut app);
+
+        let wheel = MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: -1.0,
+            window: Entity::PLACEHOLDER,
+        };
+
+        // The wheel only scrolls the view under the cursor
+        app.world_mut().send_event(wheel);
+        app.update();
+        assert_eq!(scroll_state(&mut app), ScrollState::default());
+
+        app.world_mut()
+            .entity_mut(view)
+            .insert(Interaction::Hovered);
+        app.world_mut().send_event(wheel);
+        app.update();
+        let state = scroll_state(&mut app);
+        assert!(state.offset.y > 0.0);
+        assert!(state.velocity.y > 0.0);
+
+        for _ in 0..20 {
+            app.update();
+        }
+        let settled = scroll_state(&mut app);
+        assert!(settled.offset.y > state.offset.y);
+        assert_eq!(settled.velocity, Vec2::ZERO);
+    }
+}
@@ -40,9 +40,9 @@ impl MavericNode for SpriteNode {
                 .advanced(|args, commands| {
                     if args.is_hot() {
                         let node = args.node;
-                        let server: &AssetServer = commands
-                            .get_res_untracked()
-                            .expect("Could not get asset server");
+                        let Some(server) = commands.get_res_or_report::<AssetServer>() else {
+                            return;
+                        };
                         let image_handle: Handle<Image> = server.load(node.texture_path);
 
                         commands.insert(image_handle);
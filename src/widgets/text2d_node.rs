@@ -44,7 +44,11 @@ impl<T: core::fmt::Display + PartialEq + Clone + Send + Sync + 'static> MavericN
     type Context<'w, 's> = ();
 
     fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
-        commands.insert_static_bundle((SpatialBundle::default(), TextLayoutInfo::default(), SpriteSource::default()));
+        commands.insert_static_bundle((
+            SpatialBundle::default(),
+            TextLayoutInfo::default(),
+            SpriteSource::default(),
+        ));
         commands.node_to_bundle(|x| &x.text_anchor);
         commands.node_to_component(|x| &x.text_2d_bounds, |l, r| text_2d_bound_compare(*l, *r));
 
@@ -53,10 +57,10 @@ impl<T: core::fmt::Display + PartialEq + Clone + Send + Sync + 'static> MavericN
                 .advanced(|args, commands| {
                     if args.is_hot() {
                         let node = args.node;
-                        let server: &AssetServer = commands
-                            .get_res_untracked()
-                            .expect("Could not get asset server");
-                        let font = server.load(node.font);
+                        let font = commands
+                            .get_res_or_report::<AssetServer>()
+                            .map(|server| server.load(node.font))
+                            .unwrap_or_default();
                         let mut bundle = Text::from_section(
                             node.text.to_string(),
                             TextStyle {
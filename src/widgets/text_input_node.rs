@@ -0,0 +1,704 @@
+use crate::prelude::*;
+use bevy::{
+    ecs::event::ManualEventReader,
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    ui::UiSystem,
+    window::Ime,
+};
+use std::{any::Any, ops::Range, sync::Arc};
+
+#[derive(Debug, Default)]
+pub struct TextInputPlugin;
+
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TextInputChanged>()
+            .add_event::<FocusNavigation>()
+            .add_systems(
+                PreUpdate,
+                (
+                    activate_text_inputs,
+                    edit_text_inputs,
+                    // After editing so that the key which confirmed does not also stop editing
+                    activate_focused_text_inputs,
+                    render_text_inputs,
+                )
+                    .chain()
+                    .after(UiSystem::Focus),
+            );
+    }
+}
+
+/// An editable line of text.
+///
+/// `value` should come from your model. Edits are passed to `on_change` and sent as `TextInputChanged` events,
+/// so the model can be updated and the node re-rendered with the new value.
+/// Click the node to start editing and press escape or enter, or click elsewhere, to stop.
+/// Give it a `Focusable` with `with_bundle` to also start editing when focus is confirmed with the keyboard or a gamepad
+#[derive(PartialEq, Debug, Clone)]
+pub struct TextInputNode<S: IntoBundle<B = Style>> {
+    pub style: S,
+    pub value: String,
+    pub placeholder: &'static str,
+    pub max_length: Option<usize>,
+    /// Show every character as a dot
+    pub password: bool,
+    pub font: &'static str,
+    pub font_size: f32,
+    pub color: Color,
+    pub placeholder_color: Color,
+    pub selection_color: Color,
+    pub justify_text: JustifyText,
+    pub on_change: Option<TextInputHandler>,
+}
+
+impl<S: IntoBundle<B = Style>> MavericNode for TextInputNode<S> {
+    type Context<'w, 's> = ();
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.insert_static_bundle((TextBundle::default(), Interaction::default()));
+        commands.node_to_bundle(|x| &x.style);
+
+        commands.advanced(|args, commands| {
+            if !args.is_hot() {
+                return;
+            }
+            let node = args.node;
+            let font = commands
+                .get_res_or_report::<AssetServer>()
+                .map(|server| server.load(node.font))
+                .unwrap_or_default();
+
+            commands.insert(TextInputConfig {
+                text_style: TextStyle {
+                    font,
+                    font_size: node.font_size,
+                    color: node.color,
+                },
+                placeholder: node.placeholder,
+                placeholder_color: node.placeholder_color,
+                selection_color: node.selection_color,
+                max_length: node.max_length,
+                password: node.password,
+                on_change: node.on_change.clone(),
+            });
+            commands.insert(Text::default().with_justify(node.justify_text));
+
+            // Only replace the state if the value was changed from outside
+            let existing = commands
+                .get::<TextInputState>()
+                .map(|state| (state.value == node.value, state.active));
+            match existing {
+                Some((true, _)) => {}
+                Some((false, active)) => {
+                    let mut state = TextInputState::new(node.value.clone());
+                    state.active = active;
+                    commands.insert(state);
+                }
+                None => commands.insert(TextInputState::new(node.value.clone())),
+            }
+        });
+    }
+
+    fn set_children<R: MavericRoot>(
+        _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+    ) {
+    }
+}
+
+/// Sent whenever the user edits a text input
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct TextInputChanged {
+    pub entity: Entity,
+    pub value: String,
+}
+
+/// Something to do when the value of a text input is edited.
+///
+/// Handlers are compared by value so that a text input node is not hot just because its handler was recreated
+#[derive(Clone)]
+pub struct TextInputHandler(Arc<dyn ErasedTextInputHandler>);
+
+impl TextInputHandler {
+    /// Update a resource with the new value
+    pub fn update_resource<R: Resource>(update: fn(&mut R, &str)) -> Self {
+        Self(Arc::new(UpdateResourceHandler { update }))
+    }
+
+    fn run(&self, value: &str, commands: &mut Commands) {
+        self.0.run(value, commands);
+    }
+}
+
+impl PartialEq for TextInputHandler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.dyn_eq(other.0.as_any())
+    }
+}
+
+impl std::fmt::Debug for TextInputHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+trait ErasedTextInputHandler: Send + Sync + std::fmt::Debug + 'static {
+    fn run(&self, value: &str, commands: &mut Commands);
+    fn as_any(&self) -> &dyn Any;
+    fn dyn_eq(&self, other: &dyn Any) -> bool;
+}
+
+struct UpdateResourceHandler<R> {
+    update: fn(&mut R, &str),
+}
+
+impl<R> std::fmt::Debug for UpdateResourceHandler<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateResourceHandler")
+            .field("resource", &std::any::type_name::<R>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Resource> ErasedTextInputHandler for UpdateResourceHandler<R> {
+    fn run(&self, value: &str, commands: &mut Commands) {
+        let value = value.to_string();
+        let update = self.update;
+        commands.add(move |world: &mut World| {
+            if let Some(mut resource) = world.get_resource_mut::<R>() {
+                update(&mut resource, &value);
+            }
+        });
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other
+            .downcast_ref::<Self>()
+            .is_some_and(|other| std::ptr::fn_addr_eq(other.update, self.update))
+    }
+}
+
+/// How a text input is displayed and what happens when it is edited. This is inserted by `TextInputNode`
+#[derive(Debug, Clone, Component)]
+pub struct TextInputConfig {
+    pub text_style: TextStyle,
+    pub placeholder: &'static str,
+    pub placeholder_color: Color,
+    pub selection_color: Color,
+    pub max_length: Option<usize>,
+    pub password: bool,
+    pub on_change: Option<TextInputHandler>,
+}
+
+/// The value being edited and the caret and selection.
+/// Positions are measured in characters
+#[derive(Debug, Clone, PartialEq, Eq, Default, Component)]
+pub struct TextInputState {
+    pub value: String,
+    pub caret: usize,
+    /// The other end of the selection
+    pub anchor: Option<usize>,
+    /// Whether this is receiving keyboard input
+    pub active: bool,
+}
+
+impl TextInputState {
+    #[must_use]
+    pub fn new(value: String) -> Self {
+        let caret = value.chars().count();
+        Self {
+            value,
+            caret,
+            anchor: None,
+            active: false,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_index(&self, position: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(position)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+
+    /// The selected characters
+    #[must_use]
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor.filter(|anchor| *anchor != self.caret)?;
+        Some(anchor.min(self.caret)..anchor.max(self.caret))
+    }
+
+    /// Move the caret, extending the selection if `select` is true
+    pub fn move_caret(&mut self, position: usize, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.caret);
+        } else {
+            self.anchor = None;
+        }
+        self.caret = position.min(self.len());
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.caret = self.len();
+    }
+
+    /// Returns true if anything was deleted
+    fn delete_range(&mut self, range: Range<usize>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        let bytes = self.byte_index(range.start)..self.byte_index(range.end);
+        self.value.replace_range(bytes, "");
+        self.caret = range.start;
+        self.anchor = None;
+        true
+    }
+
+    /// Replace the selection with `text`, truncating it to fit within `max_length`.
+    /// Returns true if the value changed
+    pub fn insert(&mut self, text: &str, max_length: Option<usize>) -> bool {
+        let deleted = self.selection().is_some_and(|s| self.delete_range(s));
+        let remaining = max_length.map_or(usize::MAX, |max| max.saturating_sub(self.len()));
+        let text: String = text
+            .chars()
+            .filter(|c| !c.is_control())
+            .take(remaining)
+            .collect();
+        if text.is_empty() {
+            return deleted;
+        }
+
+        let index = self.byte_index(self.caret);
+        self.value.insert_str(index, &text);
+        self.caret += text.chars().count();
+        self.anchor = None;
+        true
+    }
+
+    /// Delete the selection or the character before the caret
+    pub fn backspace(&mut self) -> bool {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.caret.saturating_sub(1)..self.caret);
+        self.delete_range(range)
+    }
+
+    /// Delete the selection or the character after the caret
+    pub fn delete(&mut self) -> bool {
+        let range = self
+            .selection()
+            .unwrap_or_else(|| self.caret..(self.caret + 1).min(self.len()));
+        self.delete_range(range)
+    }
+
+    /// The text sections to display
+    fn sections(&self, config: &TextInputConfig) -> Vec<TextSection> {
+        let section = |value: String, color: Color| TextSection {
+            value,
+            style: TextStyle {
+                color,
+                ..config.text_style.clone()
+            },
+        };
+
+        if self.value.is_empty() && !self.active {
+            return vec![section(
+                config.placeholder.to_string(),
+                config.placeholder_color,
+            )];
+        }
+
+        let normal = config.text_style.color;
+        let selection = self.selection().filter(|_| self.active);
+        let mut sections: Vec<TextSection> = vec![];
+        let mut push = |c: char, color: Color| match sections.last_mut() {
+            Some(last) if last.style.color == color => last.value.push(c),
+            _ => sections.push(section(c.to_string(), color)),
+        };
+
+        for (index, c) in self.value.chars().enumerate() {
+            if self.active && index == self.caret {
+                push('|', normal);
+            }
+            let c = if config.password { '•' } else { c };
+            let selected = selection.as_ref().is_some_and(|s| s.contains(&index));
+            push(
+                c,
+                if selected {
+                    config.selection_color
+                } else {
+                    normal
+                },
+            );
+        }
+        if self.active && self.caret == self.len() {
+            push('|', normal);
+        }
+        sections
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn activate_text_inputs(
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    mut query: Query<(&Interaction, &mut TextInputState)>,
+    mut windows: Query<&mut Window>,
+) {
+    if query.is_empty() || !mouse.is_some_and(|mouse| mouse.just_pressed(MouseButton::Left)) {
+        return;
+    }
+
+    let mut any_active = false;
+    let mut any_changed = false;
+    for (interaction, mut state) in &mut query {
+        let active = *interaction == Interaction::Pressed;
+        any_active |= active;
+        any_changed |= set_active(&mut state, active);
+    }
+
+    // Leave the windows alone unless this click started or stopped editing
+    if any_changed {
+        set_ime_enabled(&mut windows, any_active);
+    }
+}
+
+/// Start editing the focused text input when focus is confirmed
+#[allow(clippy::needless_pass_by_value)]
+fn activate_focused_text_inputs(
+    mut events: EventReader<FocusNavigation>,
+    focus: Option<Res<Focus>>,
+    mut query: Query<(&Focusable, &mut TextInputState)>,
+    mut windows: Query<&mut Window>,
+) {
+    if !events
+        .read()
+        .any(|navigation| *navigation == FocusNavigation::Confirm)
+    {
+        return;
+    }
+    let Some(focused) = focus.and_then(|focus| focus.focused) else {
+        return;
+    };
+    if !query.iter().any(|(focusable, _)| focusable.key == focused) {
+        return;
+    }
+
+    let mut any_changed = false;
+    for (focusable, mut state) in &mut query {
+        any_changed |= set_active(&mut state, focusable.key == focused);
+    }
+
+    if any_changed {
+        set_ime_enabled(&mut windows, true);
+    }
+}
+
+/// Returns whether the state changed
+fn set_active(state: &mut Mut<TextInputState>, active: bool) -> bool {
+    if state.active == active {
+        return false;
+    }
+    state.active = active;
+    state.anchor = None;
+    true
+}
+
+fn set_ime_enabled(windows: &mut Query<&mut Window>, enabled: bool) {
+    for mut window in windows {
+        if window.ime_enabled != enabled {
+            window.ime_enabled = enabled;
+        }
+    }
+}
+
+/// These events are only registered if the app has the input and window plugins
+type TextEventReaders = (ManualEventReader<KeyboardInput>, ManualEventReader<Ime>);
+
+#[allow(clippy::needless_pass_by_value)]
+fn edit_text_inputs(
+    mut commands: Commands,
+    keyboard_events: Option<Res<Events<KeyboardInput>>>,
+    ime_events: Option<Res<Events<Ime>>>,
+    mut readers: Local<TextEventReaders>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut query: Query<(Entity, &mut TextInputState, &TextInputConfig)>,
+    mut changed_events: EventWriter<TextInputChanged>,
+) {
+    let (keyboard_reader, ime_reader) = &mut *readers;
+    let keyboard_events: Vec<_> = keyboard_events
+        .as_ref()
+        .map(|events| {
+            keyboard_reader
+                .read(events)
+                .filter(|e| e.state == ButtonState::Pressed)
+                .collect()
+        })
+        .unwrap_or_default();
+    let commits: Vec<_> = ime_events
+        .as_ref()
+        .map(|events| {
+            ime_reader
+                .read(events)
+                .filter_map(|e| match e {
+                    Ime::Commit { value, .. } => Some(value.as_str()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if keyboard_events.is_empty() && commits.is_empty() {
+        return;
+    }
+
+    let shift = keys
+        .as_ref()
+        .is_some_and(|k| k.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]));
+    let control = keys.as_ref().is_some_and(|k| {
+        k.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ])
+    });
+
+    for (entity, mut state, config) in &mut query {
+        if !state.active {
+            continue;
+        }
+        let state = state.as_mut();
+        let mut changed = false;
+
+        for event in &keyboard_events {
+            match &event.logical_key {
+                Key::Character(c) if control && c.eq_ignore_ascii_case("a") => state.select_all(),
+                Key::Character(_) if control => {}
+                Key::Character(c) => changed |= state.insert(c, config.max_length),
+                Key::Space => changed |= state.insert(" ", config.max_length),
+                Key::Backspace => changed |= state.backspace(),
+                Key::Delete => changed |= state.delete(),
+                Key::ArrowLeft => match state.selection() {
+                    Some(selection) if !shift => state.move_caret(selection.start, false),
+                    _ => state.move_caret(state.caret.saturating_sub(1), shift),
+                },
+                Key::ArrowRight => match state.selection() {
+                    Some(selection) if !shift => state.move_caret(selection.end, false),
+                    _ => state.move_caret(state.caret + 1, shift),
+                },
+                Key::Home => state.move_caret(0, shift),
+                Key::End => state.move_caret(usize::MAX, shift),
+                Key::Enter | Key::Escape => {
+                    state.active = false;
+                    state.anchor = None;
+                }
+                _ => {}
+            }
+        }
+
+        for value in &commits {
+            changed |= state.insert(value, config.max_length);
+        }
+
+        if changed {
+            if let Some(on_change) = &config.on_change {
+                on_change.run(&state.value, &mut commands);
+            }
+            changed_events.send(TextInputChanged {
+                entity,
+                value: state.value.clone(),
+            });
+        }
+    }
+}
+
+type ChangedTextInputQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static TextInputState,
+        &'static TextInputConfig,
+        &'static mut Text,
+    ),
+    Or<(Changed<TextInputState>, Changed<TextInputConfig>)>,
+>;
+
+fn render_text_inputs(mut query: ChangedTextInputQuery) {
+    for (state, config, mut text) in &mut query {
+        text.sections = state.sections(config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextInputState;
+    use crate::{prelude::*, with_bundle::CanWithBundle};
+    use bevy::{
+        input::{
+            keyboard::{Key, KeyboardInput},
+            ButtonState,
+        },
+        time::TimePlugin,
+    };
+
+    #[test]
+    pub fn test_text_input_state() {
+        let mut state = TextInputState::new("héllo".to_string());
+        assert_eq!(state.caret, 5);
+
+        assert!(state.backspace());
+        assert_eq!(state.value, "héll");
+
+        state.move_caret(1, false);
+        state.move_caret(3, true);
+        assert_eq!(state.selection(), Some(1..3));
+        assert!(state.insert("ipp", None));
+        assert_eq!(state.value, "hippl");
+        assert_eq!(state.caret, 4);
+
+        assert!(state.delete());
+        assert_eq!(state.value, "hipp");
+
+        // Only one more character fits
+        assert!(state.insert("yz", Some(5)));
+        assert_eq!(state.value, "hippy");
+        assert!(!state.insert("!", Some(5)));
+
+        state.select_all();
+        assert!(state.backspace());
+        assert_eq!(state.value, "");
+        assert!(!state.backspace());
+    }
+
+    #[derive(Debug, Default, Resource)]
+    struct Model {
+        name: String,
+    }
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Model>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(
+                0,
+                TextInputNode {
+                    style: Style::default(),
+                    value: context.name.clone(),
+                    placeholder: "Name",
+                    max_length: Some(4),
+                    password: false,
+                    font: "font.ttf",
+                    font_size: 12.0,
+                    color: Color::BLACK,
+                    placeholder_color: Color::WHITE,
+                    selection_color: Color::WHITE,
+                    justify_text: JustifyText::Left,
+                    on_change: Some(TextInputHandler::update_resource(
+                        |model: &mut Model, value| value.clone_into(&mut model.name),
+                    )),
+                }
+                .with_bundle(Focusable::new("name")),
+                &(),
+            );
+        }
+    }
+
+    fn text(app: &mut App) -> String {
+        let world = app.world_mut();
+        let text = world.query::<&Text>().single(world);
+        text.sections.iter().map(|s| s.value.as_str()).collect()
+    }
+
+    fn type_key(app: &mut App, logical_key: Key) {
+        app.world_mut().send_event(KeyboardInput {
+            key_code: KeyCode::KeyA,
+            logical_key,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+    }
+
+    #[test]
+    pub fn test_text_input_node() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        // This is normally registered by the input plugin
+        app.add_event::<KeyboardInput>();
+        app.init_resource::<Model>();
+        app.register_maveric::<Root>();
+        app.update();
+        assert_eq!(text(&mut app), "Name");
+
+        let world = app.world_mut();
+        world
+            .query::<&mut TextInputState>()
+            .single_mut(world)
+            .active = true;
+        app.update();
+        assert_eq!(text(&mut app), "|");
+
+        for c in ["a", "b", "c", "d", "e"] {
+            type_key(&mut app, Key::Character(c.into()));
+        }
+        type_key(&mut app, Key::Backspace);
+        app.update();
+        assert_eq!(app.world().resource::<Model>().name, "abc");
+        assert_eq!(text(&mut app), "abc|");
+
+        // Changing the model replaces the value
+        app.world_mut().resource_mut::<Model>().name = "xy".to_string();
+        app.update();
+        app.update();
+        assert_eq!(text(&mut app), "xy|");
+
+        type_key(&mut app, Key::Escape);
+        assert_eq!(text(&mut app), "xy");
+
+        // Confirming focus on the input starts editing
+        app.world_mut().resource_mut::<Focus>().focus("name");
+        app.world_mut().send_event(FocusNavigation::Confirm);
+        app.update();
+        assert_eq!(text(&mut app), "xy|");
+
+        type_key(&mut app, Key::Character("z".into()));
+        assert_eq!(text(&mut app), "xyz|");
+
+        // Clicking elsewhere stops editing and disables ime
+        let window = app
+            .world_mut()
+            .spawn(Window {
+                ime_enabled: true,
+                ..default()
+            })
+            .id();
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        mouse.press(MouseButton::Left);
+        app.insert_resource(mouse);
+        app.update();
+        assert_eq!(text(&mut app), "xyz");
+        assert!(!app.world().get::<Window>(window).unwrap().ime_enabled);
+
+        // Clicks which don't change any input leave the window alone
+        app.world_mut()
+            .get_mut::<Window>(window)
+            .unwrap()
+            .ime_enabled = true;
+        app.update();
+        assert!(app.world().get::<Window>(window).unwrap().ime_enabled);
+    }
+}
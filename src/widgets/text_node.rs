@@ -19,10 +19,10 @@ impl<T: Into<String> + PartialEq + Clone + Send + Sync + 'static> MavericNode fo
 
         commands.advanced(|args, commands| {
             let node = args.node;
-            let server: &AssetServer = commands
-                .get_res_untracked()
-                .expect("Could not get asset server");
-            let font = server.load(node.font);
+            let font = commands
+                .get_res_or_report::<AssetServer>()
+                .map(|server| server.load(node.font))
+                .unwrap_or_default();
             let mut bundle = Text::from_section(
                 node.text.clone(),
                 TextStyle {
@@ -0,0 +1,348 @@
+use crate::prelude::*;
+use crate::transition::speed::ScalarSpeed;
+use crate::transition::ui_lenses::{StyleRightLens, StyleTopLens};
+use std::{marker::PhantomData, time::Duration};
+
+/// A notification shown by a `ToastStack`
+pub trait Toast: PartialEq + Clone + Send + Sync + 'static {
+    type Node: for<'w, 's> MavericNode<Context<'w, 's> = ()>;
+
+    fn to_node(&self) -> Self::Node;
+
+    /// How long this is shown for
+    fn duration(&self) -> Duration {
+        Duration::from_secs(3)
+    }
+}
+
+pub trait CanRegisterToasts {
+    /// Add the `Toasts<T>` resource and queue a toast for each `ShowToast<T>` event
+    fn register_toasts<T: Toast>(&mut self) -> &mut Self;
+}
+
+impl CanRegisterToasts for App {
+    fn register_toasts<T: Toast>(&mut self) -> &mut Self {
+        self.init_resource::<Toasts<T>>()
+            .add_event::<ShowToast<T>>()
+            .add_systems(Update, queue_toasts::<T>)
+    }
+}
+
+/// Send this to show a toast
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct ShowToast<T: Toast>(pub T);
+
+/// The toasts being shown, oldest first.
+/// Each is removed when its duration has elapsed
+#[derive(Debug, Clone, PartialEq, Eq, Resource)]
+pub struct Toasts<T: Toast> {
+    toasts: Vec<(u32, T)>,
+    next_id: u32,
+}
+
+impl<T: Toast> Default for Toasts<T> {
+    fn default() -> Self {
+        Self {
+            toasts: vec![],
+            next_id: 0,
+        }
+    }
+}
+
+impl<T: Toast> Toasts<T> {
+    /// Show a toast below the others. Returns its id
+    pub fn push(&mut self, toast: T) -> u32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.toasts.push((id, toast));
+        id
+    }
+
+    /// Dismiss the toast with this id
+    pub fn remove(&mut self, id: u32) -> Option<T> {
+        let index = self.toasts.iter().position(|(i, _)| *i == id)?;
+        Some(self.toasts.remove(index).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.toasts.iter().map(|(_, toast)| toast)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn queue_toasts<T: Toast>(mut events: EventReader<ShowToast<T>>, mut toasts: ResMut<Toasts<T>>) {
+    for ShowToast(toast) in events.read() {
+        toasts.push(toast.clone());
+    }
+}
+
+/// Shows the toasts in `Toasts<T>` in a column at the top right of its parent.
+///
+/// Toasts slide in from the right and out again when they expire, and the others slide up to fill the gap.
+/// You must register the `StyleTopLens` and `StyleRightLens` transitions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToastStack<T: Toast> {
+    /// The height of each toast, in logical pixels
+    pub toast_height: f32,
+    /// The space between toasts, in logical pixels
+    pub gap: f32,
+    /// How far toasts slide in from, in logical pixels
+    pub slide_distance: f32,
+    /// How long toasts take to slide in, out, or up
+    pub transition_duration: Duration,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Toast> ToastStack<T> {
+    #[must_use]
+    pub const fn new(
+        toast_height: f32,
+        gap: f32,
+        slide_distance: f32,
+        transition_duration: Duration,
+    ) -> Self {
+        Self {
+            toast_height,
+            gap,
+            slide_distance,
+            transition_duration,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Toast> MavericNode for ToastStack<T> {
+    type Context<'w, 's> = Res<'w, Toasts<T>>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.ignore_node().ignore_context().insert(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            ..default()
+        });
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, toasts, mut commands)) = commands.ordered_children_with_node_and_context()
+        else {
+            return;
+        };
+
+        let spacing = node.toast_height + node.gap;
+        let reflow_speed = ScalarSpeed::new(spacing / node.transition_duration.as_secs_f32());
+        let hidden = Val::Px(-node.slide_distance);
+
+        for (index, (id, toast)) in toasts.toasts.iter().enumerate() {
+            let item = ToastItem {
+                id: *id,
+                height: node.toast_height,
+                toast: toast.clone(),
+            }
+            .with_transition_to::<StyleTopLens>(
+                Val::Px(index as f32 * spacing),
+                reflow_speed,
+                Some(Ease::CubicOut),
+            )
+            .with_transition_in_out::<StyleRightLens>(
+                hidden,
+                Val::Px(0.0),
+                hidden,
+                node.transition_duration,
+                node.transition_duration,
+                Some(Ease::CubicOut),
+                Some(Ease::CubicIn),
+            );
+
+            commands.add_child(*id, item, &());
+        }
+    }
+}
+
+/// Positions a toast and removes it from `Toasts<T>` when it expires
+#[derive(Debug, Clone, PartialEq)]
+struct ToastItem<T: Toast> {
+    id: u32,
+    height: f32,
+    toast: T,
+}
+
+impl<T: Toast> MavericNode for ToastItem<T> {
+    type Context<'w, 's> = ();
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.insert_static_bundle(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            ..default()
+        });
+
+        commands.advanced(|args, commands| {
+            if args.event == SetEvent::Created {
+                let id = args.node.id;
+                commands.insert(ScheduledChange {
+                    remaining: args.node.toast.duration(),
+                    boxed_change: Box::new(move |ec| {
+                        ec.commands().add(move |world: &mut World| {
+                            if let Some(mut toasts) = world.get_resource_mut::<Toasts<T>>() {
+                                toasts.remove(id);
+                            }
+                        });
+                    }),
+                });
+            }
+            if !args.is_hot() {
+                return;
+            }
+            // Keep the position, which is controlled by transitions
+            let (top, right) = commands
+                .get::<Style>()
+                .map_or((Val::Auto, Val::Auto), |s| (s.top, s.right));
+            commands.insert(Style {
+                position_type: PositionType::Absolute,
+                top,
+                right,
+                height: Val::Px(args.node.height),
+                ..default()
+            });
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+            return;
+        };
+        commands.add_child(0, node.toast.to_node(), &());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::transition::ui_lenses::{StyleRightLens, StyleTopLens};
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Message(&'static str, Duration);
+
+    impl Toast for Message {
+        type Node = Label;
+
+        fn to_node(&self) -> Self::Node {
+            Label(self.0)
+        }
+
+        fn duration(&self) -> Duration {
+            self.1
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label(&'static str);
+
+    impl MavericNode for Label {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Toasts<Message>>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(
+                0,
+                ToastStack::<Message>::new(40.0, 10.0, 300.0, Duration::from_secs(1)),
+                context,
+            );
+        }
+    }
+
+    /// The labels of the toasts which are not leaving and where they are going
+    fn toasts(app: &mut App) -> Vec<(&'static str, Val)> {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<
+            (&Children, &Style, Option<&Transition<StyleTopLens>>),
+            Without<ScheduledForDeletion>,
+        >();
+        let mut toasts: Vec<_> = query
+            .iter(world)
+            .filter_map(|(children, style, transition)| {
+                let label = world.get::<MavericNodeComponent<Label>>(children[0])?;
+                let top = transition
+                    .and_then(|t| t.destination().copied())
+                    .unwrap_or(style.top);
+                Some((label.node.0, top))
+            })
+            .collect();
+        toasts.sort_by_key(|(label, _)| *label);
+        toasts
+    }
+
+    #[test]
+    pub fn test_toasts() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.register_toasts::<Message>();
+        app.register_transition::<StyleTopLens>();
+        app.register_transition::<StyleRightLens>();
+        app.register_maveric::<Root>();
+        app.update();
+
+        app.world_mut()
+            .send_event(ShowToast(Message("saved", Duration::ZERO)));
+        app.world_mut()
+            .send_event(ShowToast(Message("unlocked", Duration::from_secs(100))));
+        app.update();
+        app.update();
+        assert_eq!(
+            toasts(&mut app),
+            vec![("saved", Val::Px(0.0)), ("unlocked", Val::Px(50.0))]
+        );
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<Toasts<Message>>().len(), 1);
+        assert_eq!(
+            toasts(&mut app),
+            vec![("unlocked", Val::Px(0.0))],
+            "The remaining toast should move up"
+        );
+        let world = app.world_mut();
+        assert_eq!(
+            world.query::<&ScheduledForDeletion>().iter(world).count(),
+            1,
+            "The expired toast should linger while it slides out"
+        );
+    }
+}
@@ -0,0 +1,586 @@
+use crate::{has_changed::HasChanged, prelude::*};
+use bevy::ui::{FocusPolicy, UiSystem};
+use bevy::window::PrimaryWindow;
+use std::{marker::PhantomData, time::Duration};
+
+/// Tooltips are drawn above everything with a lower global z-index, including modals
+const TOOLTIP_Z_INDEX: i32 = 2000;
+
+#[derive(Debug, Default)]
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, position_tooltips.before(UiSystem::Layout));
+    }
+}
+
+/// The content of a tooltip shown by a `TooltipOverlay`
+pub trait Tooltip: PartialEq + Clone + Send + Sync + 'static {
+    type Node: for<'w, 's> MavericNode<Context<'w, 's> = ()>;
+
+    fn to_node(&self) -> Self::Node;
+}
+
+pub trait CanRegisterTooltips {
+    /// Add the `Tooltips<T>` resource and show the tooltip of whichever `WithTooltip` node has been hovered for long enough
+    fn register_tooltips<T: Tooltip>(&mut self) -> &mut Self;
+}
+
+impl CanRegisterTooltips for App {
+    fn register_tooltips<T: Tooltip>(&mut self) -> &mut Self {
+        self.init_resource::<Tooltips<T>>()
+            .add_systems(PreUpdate, hover_tooltips::<T>.after(UiSystem::Focus))
+    }
+}
+
+/// The tooltip being shown, if any
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct Tooltips<T: Tooltip> {
+    /// How long a node must be hovered before its tooltip appears
+    pub delay: Duration,
+    shown: Option<ShownTooltip<T>>,
+    next_id: u32,
+}
+
+impl<T: Tooltip> Default for Tooltips<T> {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(500))
+    }
+}
+
+impl<T: Tooltip> Tooltips<T> {
+    #[must_use]
+    pub const fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            shown: None,
+            next_id: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn shown(&self) -> Option<&ShownTooltip<T>> {
+        self.shown.as_ref()
+    }
+
+    /// Hide the tooltip until the pointer moves to another node
+    pub fn hide(&mut self) {
+        self.shown = None;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShownTooltip<T: Tooltip> {
+    id: u32,
+    /// The hovered entity
+    pub anchor: Entity,
+    /// The hovered entity's rect, in logical pixels
+    pub rect: Rect,
+    pub tooltip: T,
+}
+
+/// The tooltip shown when this entity is hovered. Added by `WithTooltip`
+#[derive(Debug, Clone, PartialEq, Eq, Component)]
+pub struct TooltipSource<T: Tooltip>(pub T);
+
+type TooltipSourceQuery<'w, 's, T> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Interaction,
+        &'static TooltipSource<T>,
+        &'static Node,
+        &'static GlobalTransform,
+    ),
+>;
+
+#[allow(clippy::needless_pass_by_value)]
+fn hover_tooltips<T: Tooltip>(
+    time: Res<Time>,
+    mut tooltips: ResMut<Tooltips<T>>,
+    mut hovered: Local<Option<(Entity, Duration)>>,
+    sources: TooltipSourceQuery<T>,
+) {
+    // If nested tooltip sources are hovered, use the top one
+    let top = sources
+        .iter()
+        .filter(|(_, interaction, ..)| **interaction == Interaction::Hovered)
+        .max_by_key(|(_, _, _, node, _)| node.stack_index());
+
+    let Some((entity, _, source, node, transform)) = top else {
+        *hovered = None;
+        if tooltips.shown.is_some() {
+            tooltips.shown = None;
+        }
+        return;
+    };
+
+    let previous_elapsed = hovered
+        .filter(|(previous, _)| *previous == entity)
+        .map(|(_, elapsed)| elapsed);
+    let elapsed = previous_elapsed.map_or(Duration::ZERO, |elapsed| elapsed + time.delta());
+    *hovered = Some((entity, elapsed));
+
+    if elapsed < tooltips.delay {
+        if tooltips.shown.is_some() {
+            tooltips.shown = None;
+        }
+        return;
+    }
+
+    let rect = node.logical_rect(transform);
+
+    let id = match &tooltips.shown {
+        Some(shown) if shown.anchor == entity => {
+            if shown.rect == rect && shown.tooltip == source.0 {
+                return;
+            }
+            shown.id
+        }
+        // Hidden with `Tooltips::hide`
+        None if previous_elapsed.is_some_and(|elapsed| elapsed >= tooltips.delay) => return,
+        _ => {
+            let id = tooltips.next_id;
+            tooltips.next_id = id.wrapping_add(1);
+            id
+        }
+    };
+
+    tooltips.shown = Some(ShownTooltip {
+        id,
+        anchor: entity,
+        rect,
+        tooltip: source.0.clone(),
+    });
+}
+
+/// Wraps a node so that its tooltip is shown when it is hovered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithTooltip<N: MavericNode, T: Tooltip> {
+    pub node: N,
+    pub tooltip: T,
+}
+
+impl<N: MavericNode, T: Tooltip> MavericNode for WithTooltip<N, T> {
+    type Context<'w, 's> = N::Context<'w, 's>;
+
+    fn on_changed(
+        &self,
+        previous: &Self,
+        context: &Self::Context<'_, '_>,
+        world: &World,
+        entity_commands: &mut bevy::ecs::system::EntityCommands,
+    ) {
+        if context.has_changed() | (!self.node.eq(&previous.node)) {
+            N::on_changed(&self.node, &previous.node, context, world, entity_commands);
+        }
+    }
+
+    fn on_created(
+        &self,
+        context: &Self::Context<'_, '_>,
+        world: &World,
+        entity_commands: &mut bevy::ecs::system::EntityCommands,
+    ) {
+        N::on_created(&self.node, context, world, entity_commands);
+    }
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.scope(|commands| {
+            let commands = commands.map_node(|x| &x.node);
+            N::set_components(commands);
+        });
+
+        commands
+            .ignore_context()
+            .map_node(|x| &x.tooltip)
+            .advanced(|args, commands| {
+                if args.event == SetEvent::Created {
+                    commands.insert(Interaction::default());
+                }
+                if args.is_hot() {
+                    commands.insert(TooltipSource(args.node.clone()));
+                }
+            })
+            .finish();
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        N::set_children(commands.map_args(|x| &x.node));
+    }
+
+    fn on_deleted(&self, commands: &mut ComponentCommands) -> DeletionPolicy {
+        self.node.on_deleted(commands)
+    }
+
+    fn should_recreate(&self, previous: &Self, context: &Self::Context<'_, '_>) -> bool {
+        self.node.should_recreate(&previous.node, context)
+    }
+}
+
+pub trait CanWithTooltip: MavericNode {
+    fn with_tooltip<T: Tooltip>(self, tooltip: T) -> WithTooltip<Self, T> {
+        WithTooltip {
+            node: self,
+            tooltip,
+        }
+    }
+}
+
+impl<N: MavericNode> CanWithTooltip for N {}
+
+/// Shows the tooltip in `Tooltips<T>` next to the hovered node.
+///
+/// This should be a full-size child of the root so that tooltips are not clipped by the parents of the hovered node.
+/// Tooltips are placed below the hovered node, or above it if there is no room, and are kept inside the window.
+/// Tooltips fade in and out so you must register the `BackgroundColorLens` transition
+#[derive(Debug, Clone, PartialEq)]
+pub struct TooltipOverlay<T: Tooltip> {
+    pub background_color: Color,
+    pub padding: UiRect,
+    /// The space between a tooltip and the hovered node, in logical pixels
+    pub gap: f32,
+    /// How long tooltips take to appear and disappear
+    pub transition_duration: Duration,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Tooltip> TooltipOverlay<T> {
+    #[must_use]
+    pub const fn new(background_color: Color, transition_duration: Duration) -> Self {
+        Self {
+            background_color,
+            padding: UiRect::all(Val::Px(4.0)),
+            gap: 4.0,
+            transition_duration,
+            phantom: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_padding(mut self, padding: UiRect) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+impl<T: Tooltip> MavericNode for TooltipOverlay<T> {
+    type Context<'w, 's> = Res<'w, Tooltips<T>>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.ignore_node().ignore_context().insert(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            focus_policy: FocusPolicy::Pass,
+            z_index: ZIndex::Global(TOOLTIP_Z_INDEX),
+            ..default()
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, tooltips, mut commands)) =
+            commands.unordered_children_with_node_and_context()
+        else {
+            return;
+        };
+
+        let Some(shown) = tooltips.shown() else {
+            return;
+        };
+
+        let hidden_color = node.background_color.with_alpha(0.0);
+        let duration = node.transition_duration;
+
+        let panel = TooltipPanelNode {
+            anchor: shown.rect,
+            gap: node.gap,
+            padding: node.padding,
+            tooltip: shown.tooltip.clone(),
+        }
+        .with_transition_in_out::<BackgroundColorLens>(
+            hidden_color,
+            node.background_color,
+            hidden_color,
+            duration,
+            duration,
+            Some(Ease::CubicOut),
+            Some(Ease::CubicIn),
+        );
+
+        commands.add_child(shown.id, panel, &());
+    }
+}
+
+/// Holds the content of a tooltip
+#[derive(Debug, Clone, PartialEq)]
+struct TooltipPanelNode<T: Tooltip> {
+    anchor: Rect,
+    gap: f32,
+    padding: UiRect,
+    tooltip: T,
+}
+
+impl<T: Tooltip> MavericNode for TooltipPanelNode<T> {
+    type Context<'w, 's> = ();
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands.insert_static_bundle(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            // The tooltip must not take the hover from the node beneath it
+            focus_policy: FocusPolicy::Pass,
+            ..default()
+        });
+
+        commands.advanced(|args, commands| {
+            if !args.is_hot() {
+                return;
+            }
+            let panel = TooltipPanel {
+                anchor: args.node.anchor,
+                gap: args.node.gap,
+            };
+            // Keep the position, which is set by `position_tooltips` once the size is known
+            let (left, top) = commands.get::<Style>().map_or_else(
+                || {
+                    let position = panel.position(Vec2::ZERO, None);
+                    (Val::Px(position.x), Val::Px(position.y))
+                },
+                |style| (style.left, style.top),
+            );
+            commands.insert((
+                Style {
+                    position_type: PositionType::Absolute,
+                    left,
+                    top,
+                    padding: args.node.padding,
+                    ..default()
+                },
+                panel,
+            ));
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, mut commands)) = commands.unordered_children_with_node() else {
+            return;
+        };
+        commands.add_child(0, node.tooltip.to_node(), &());
+    }
+}
+
+/// A tooltip positioned next to the node it describes
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct TooltipPanel {
+    /// The rect of the hovered node, in logical pixels
+    pub anchor: Rect,
+    /// The space between the tooltip and the hovered node, in logical pixels
+    pub gap: f32,
+}
+
+impl TooltipPanel {
+    /// The top left of a tooltip of this size.
+    /// It is below the anchor unless it would go past the bottom of the bounds, and is kept within the bounds
+    #[must_use]
+    pub fn position(&self, size: Vec2, bounds: Option<Vec2>) -> Vec2 {
+        let mut position = Vec2::new(self.anchor.min.x, self.anchor.max.y + self.gap);
+        if let Some(bounds) = bounds {
+            if position.y + size.y > bounds.y {
+                position.y = self.anchor.min.y - self.gap - size.y;
+            }
+            position = position.clamp(Vec2::ZERO, (bounds - size).max(Vec2::ZERO));
+        }
+        position
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn position_tooltips(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut panels: Query<(&TooltipPanel, &Node, &mut Style)>,
+) {
+    let bounds = windows
+        .get_single()
+        .ok()
+        .map(|window| Vec2::new(window.width(), window.height()));
+
+    for (panel, node, mut style) in &mut panels {
+        let position = panel.position(node.size(), bounds);
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if style.left != left || style.top != top {
+            style.left = left;
+            style.top = top;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use std::time::Duration;
+
+    #[test]
+    pub fn test_tooltip_position() {
+        let panel = TooltipPanel {
+            anchor: Rect::new(100.0, 100.0, 200.0, 120.0),
+            gap: 5.0,
+        };
+        let size = Vec2::new(150.0, 30.0);
+        let bounds = Some(Vec2::new(800.0, 600.0));
+
+        assert_eq!(panel.position(size, bounds), Vec2::new(100.0, 125.0));
+        assert_eq!(
+            panel.position(size, Some(Vec2::new(200.0, 600.0))),
+            Vec2::new(50.0, 125.0),
+            "The tooltip should be kept inside the window"
+        );
+        assert_eq!(
+            panel.position(size, Some(Vec2::new(800.0, 140.0))),
+            Vec2::new(100.0, 65.0),
+            "The tooltip should go above when there is no room below"
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Hint(&'static str);
+
+    impl Tooltip for Hint {
+        type Node = Label;
+
+        fn to_node(&self) -> Self::Node {
+            Label(self.0)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Label(&'static str);
+
+    impl MavericNode for Label {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Anchor;
+
+    impl MavericNode for Anchor {
+        type Context<'w, 's> = ();
+
+        fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+            commands
+                .ignore_node()
+                .ignore_context()
+                .insert(NodeBundle::default())
+                .finish();
+        }
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Tooltips<Hint>>;
+
+        fn set_children(context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(0, Anchor.with_tooltip(Hint("help")), &());
+            commands.add_child(
+                1,
+                TooltipOverlay::<Hint>::new(Color::BLACK, Duration::from_secs(1)),
+                context,
+            );
+        }
+    }
+
+    fn set_interaction(app: &mut App, interaction: Interaction) {
+        let world = app.world_mut();
+        for mut i in world
+            .query_filtered::<&mut Interaction, With<TooltipSource<Hint>>>()
+            .iter_mut(world)
+        {
+            *i = interaction;
+        }
+    }
+
+    /// The labels in tooltips which are not leaving
+    fn labels(app: &mut App) -> Vec<&'static str> {
+        let world = app.world_mut();
+        let mut query = world
+            .query_filtered::<&Children, (With<TooltipPanel>, Without<ScheduledForDeletion>)>();
+        query
+            .iter(world)
+            .filter_map(|children| {
+                world
+                    .get::<MavericNodeComponent<Label>>(children[0])
+                    .map(|label| label.node.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    pub fn test_tooltip() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.register_tooltips::<Hint>();
+        app.register_transition::<BackgroundColorLens>();
+        app.register_maveric::<Root>();
+        app.update();
+
+        set_interaction(&mut app, Interaction::Hovered);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(
+            labels(&mut app).is_empty(),
+            "The tooltip should wait for the delay"
+        );
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(labels(&mut app), vec!["help"]);
+
+        set_interaction(&mut app, Interaction::None);
+        app.update();
+        app.update();
+        assert!(app.world().resource::<Tooltips<Hint>>().shown().is_none());
+        assert!(labels(&mut app).is_empty());
+        let world = app.world_mut();
+        assert_eq!(
+            world
+                .query_filtered::<(), (With<TooltipPanel>, With<ScheduledForDeletion>)>()
+                .iter(world)
+                .count(),
+            1,
+            "The tooltip should linger while it fades out"
+        );
+    }
+}
@@ -0,0 +1,481 @@
+use crate::prelude::*;
+use bevy::ui::UiSystem;
+use std::ops::Range;
+
+#[derive(Debug, Default)]
+pub struct VirtualListPlugin;
+
+impl Plugin for VirtualListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, refresh_resized_lists.after(UiSystem::Focus));
+    }
+}
+
+/// A vertical list which only creates the rows that are visible, plus `overscan` rows either side.
+///
+/// Rows are keyed by their position in the window of visible rows, so scrolling reuses their entities.
+/// You must register the list with `register_local_state` so that it updates when it is scrolled or resized,
+/// and register the `StyleTopLens` transition, as for `ScrollViewNode`
+#[derive(Debug)]
+pub struct VirtualListNode<S: IntoBundle<B = Style>, D, Child> {
+    pub style: S,
+    /// Passed to `get_child`. The visible rows are updated when this changes
+    pub data: D,
+    pub count: u32,
+    pub row_height: RowHeight,
+    pub overscan: u32,
+    pub physics: ScrollPhysics,
+    pub snap: ScrollSnap,
+    /// Scroll to the row with this index whenever this changes
+    pub scroll_to: Option<u32>,
+    pub get_child: fn(&D, u32) -> Option<Child>,
+}
+
+impl<S: IntoBundle<B = Style>, D: PartialEq, Child> PartialEq for VirtualListNode<S, D, Child> {
+    fn eq(&self, other: &Self) -> bool {
+        self.style == other.style
+            && self.data == other.data
+            && self.count == other.count
+            && self.row_height == other.row_height
+            && self.overscan == other.overscan
+            && self.physics == other.physics
+            && self.snap == other.snap
+            && self.scroll_to == other.scroll_to
+            && std::ptr::fn_addr_eq(self.get_child, other.get_child)
+    }
+}
+
+impl<
+        S: IntoBundle<B = Style>,
+        D: PartialEq + Send + Sync + 'static,
+        Child: MavericNode + Clone,
+    > VirtualListNode<S, D, Child>
+{
+    /// The offset to scroll to if `scroll_to` has just been set
+    fn requested_offset(&self, previous: Option<&Self>) -> Option<f32> {
+        let index = self
+            .scroll_to
+            .filter(|index| previous.and_then(|p| p.scroll_to) != Some(*index))?;
+        Some(self.row_height.top(index))
+    }
+}
+
+impl<
+        S: IntoBundle<B = Style>,
+        D: PartialEq + Send + Sync + 'static,
+        Child: MavericNode + Clone,
+    > StatefulNode for VirtualListNode<S, D, Child>
+{
+    type State = ScrollState;
+}
+
+impl<
+        S: IntoBundle<B = Style>,
+        D: PartialEq + Send + Sync + 'static,
+        Child: MavericNode + Clone,
+    > MavericNode for VirtualListNode<S, D, Child>
+{
+    type Context<'w, 's> = Child::Context<'w, 's>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        let mut commands = commands.ignore_context();
+        commands.insert_static_bundle((NodeBundle::default(), Interaction::default(), VirtualList));
+
+        commands.scope(|commands| {
+            commands
+                .advanced(|args, commands| {
+                    if !args.is_hot() {
+                        return;
+                    }
+                    let node = args.node;
+                    let mut style = node.style.clone().into_bundle();
+                    style.overflow = Overflow::clip();
+                    commands.insert((
+                        style,
+                        ScrollView {
+                            direction: ScrollDirection::Vertical,
+                            physics: node.physics,
+                            snap: node.snap,
+                        },
+                    ));
+
+                    if let Some(offset) = node.requested_offset(args.previous) {
+                        let mut state = ScrollState::default();
+                        state.scroll_to_offset(Vec2::new(0.0, offset));
+                        commands.insert(state);
+                    } else if args.event == SetEvent::Created {
+                        commands.insert(ScrollState::default());
+                    }
+                })
+                .finish();
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let (args, world, ec, alloc) = commands.into_parts();
+        if !args.should_visit_children() {
+            return;
+        }
+        let node = args.node;
+        let entity = ec.id();
+
+        let offset = node.requested_offset(args.previous).unwrap_or_else(|| {
+            world
+                .get::<ScrollState>(entity)
+                .map_or(0.0, |state| state.offset.y)
+        });
+        let view_height = world.get::<Node>(entity).map_or(0.0, |n| n.size().y);
+
+        let layout = RowLayout::new(node.row_height, node.count);
+        let visible = layout.visible_rows(offset..offset + view_height, node.overscan);
+        let window_len = visible.end - visible.start;
+
+        let rows = visible
+            .filter_map(|index| {
+                (node.get_child)(&node.data, index).map(|child| VirtualRow {
+                    slot: index % window_len,
+                    top: layout.top(index),
+                    height: layout.height(index),
+                    child,
+                })
+            })
+            .collect();
+
+        let mut commands = UnorderedChildCommands::<R>::new(ec, world, alloc, args.ambient);
+        commands.add_child(
+            0,
+            VirtualListContent {
+                height: layout.top(node.count),
+                rows,
+            },
+            args.context,
+        );
+    }
+}
+
+/// The height of each row of a virtual list
+#[derive(Debug, Clone, Copy)]
+pub enum RowHeight {
+    /// Every row has the same height, in logical pixels
+    Fixed(f32),
+    /// The height of the row with each index, in logical pixels.
+    /// This is called for every row each time the list updates
+    Measured(fn(u32) -> f32),
+}
+
+impl PartialEq for RowHeight {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Fixed(a), Self::Fixed(b)) => a == b,
+            (Self::Measured(a), Self::Measured(b)) => std::ptr::fn_addr_eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+impl RowHeight {
+    #[must_use]
+    pub fn height(&self, index: u32) -> f32 {
+        match self {
+            Self::Fixed(height) => *height,
+            Self::Measured(measure) => measure(index),
+        }
+    }
+
+    /// The distance from the top of the list to the top of the row with this index
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn top(&self, index: u32) -> f32 {
+        match self {
+            Self::Fixed(height) => index as f32 * height,
+            Self::Measured(measure) => (0..index).map(measure).sum(),
+        }
+    }
+}
+
+/// Where the rows of a list are. This is computed once each time the list updates
+#[derive(Debug)]
+struct RowLayout {
+    row_height: RowHeight,
+    count: u32,
+    /// For measured rows, the top of each row followed by the bottom of the last row
+    tops: Vec<f32>,
+}
+
+impl RowLayout {
+    fn new(row_height: RowHeight, count: u32) -> Self {
+        let tops = match row_height {
+            RowHeight::Fixed(_) => vec![],
+            RowHeight::Measured(measure) => std::iter::once(0.0)
+                .chain((0..count).scan(0.0, |top, index| {
+                    *top += measure(index);
+                    Some(*top)
+                }))
+                .collect(),
+        };
+        Self {
+            row_height,
+            count,
+            tops,
+        }
+    }
+
+    fn top(&self, index: u32) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(_) => self.row_height.top(index),
+            RowHeight::Measured(_) => self.tops[index as usize],
+        }
+    }
+
+    fn height(&self, index: u32) -> f32 {
+        match self.row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured(_) => self.tops[index as usize + 1] - self.tops[index as usize],
+        }
+    }
+
+    /// The indices of the rows which overlap `visible`, plus `overscan` rows either side
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn visible_rows(&self, visible: Range<f32>, overscan: u32) -> Range<u32> {
+        let count = self.count;
+        let Range { start, end } = match self.row_height {
+            RowHeight::Fixed(height) if height > 0.0 => {
+                let first = (visible.start / height).floor().max(0.0) as u32;
+                let last = (visible.end / height).ceil().max(0.0) as u32;
+                first..last
+            }
+            RowHeight::Fixed(_) => 0..count,
+            RowHeight::Measured(_) => {
+                // The rows which end above the visible area and the rows which start above its end
+                let first = self.tops[1..].partition_point(|bottom| *bottom <= visible.start);
+                let last = self.tops[..count as usize].partition_point(|top| *top < visible.end);
+                first as u32..last.max(first) as u32
+            }
+        };
+        start.saturating_sub(overscan).min(count)..end.saturating_add(overscan).min(count)
+    }
+}
+
+/// Marks a virtual list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+pub struct VirtualList;
+
+/// A row of a virtual list and where it goes
+#[derive(Debug, Clone, PartialEq)]
+struct VirtualRow<Child> {
+    /// The position of the row in the window of visible rows
+    slot: u32,
+    top: f32,
+    height: f32,
+    child: Child,
+}
+
+#[derive(Debug, PartialEq)]
+struct VirtualListContent<Child> {
+    height: f32,
+    rows: Vec<VirtualRow<Child>>,
+}
+
+impl<Child: MavericNode + Clone> MavericNode for VirtualListContent<Child> {
+    type Context<'w, 's> = Child::Context<'w, 's>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        let mut commands = commands.ignore_context();
+        commands.insert_static_bundle((NodeBundle::default(), ScrollContent));
+
+        commands.scope(|commands| {
+            commands
+                .advanced(|args, commands| {
+                    if !args.is_hot() {
+                        return;
+                    }
+                    // The position is controlled by the scroll systems
+                    let top = commands.get::<Style>().map_or(Val::Px(0.0), |s| s.top);
+                    commands.insert(Style {
+                        position_type: PositionType::Absolute,
+                        top,
+                        width: Val::Percent(100.0),
+                        height: Val::Px(args.node.height),
+                        ..default()
+                    });
+                })
+                .finish();
+        });
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, context, mut commands)) =
+            commands.unordered_children_with_node_and_context()
+        else {
+            return;
+        };
+        for row in &node.rows {
+            commands.add_child(row.slot, row.clone(), context);
+        }
+    }
+}
+
+impl<Child: MavericNode + Clone> MavericNode for VirtualRow<Child> {
+    type Context<'w, 's> = Child::Context<'w, 's>;
+
+    fn set_components(commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {
+        commands
+            .ignore_context()
+            .advanced(|args, commands| {
+                if !args.is_hot() {
+                    return;
+                }
+                commands.insert(NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(args.node.top),
+                        height: Val::Px(args.node.height),
+                        width: Val::Percent(100.0),
+                        ..default()
+                    },
+                    ..default()
+                });
+            })
+            .finish();
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>) {
+        let Some((node, context, mut commands)) =
+            commands.unordered_children_with_node_and_context()
+        else {
+            return;
+        };
+        commands.add_child(0, node.child.clone(), context);
+    }
+}
+
+/// Update virtual lists when their size changes so that they show the right number of rows
+fn refresh_resized_lists(mut query: Query<&mut ScrollState, (With<VirtualList>, Changed<Node>)>) {
+    for mut state in &mut query {
+        state.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimePlugin;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn measure(index: u32) -> f32 {
+        (index + 1) as f32 * 10.0
+    }
+
+    #[test]
+    pub fn test_visible_rows() {
+        let fixed = RowLayout::new(RowHeight::Fixed(10.0), 100);
+        assert_eq!(fixed.visible_rows(0.0..35.0, 0), 0..4);
+        assert_eq!(fixed.visible_rows(25.0..55.0, 2), 0..8);
+        assert_eq!(fixed.visible_rows(980.0..1020.0, 2), 96..100);
+        assert!((fixed.top(3) - 30.0).abs() < f32::EPSILON);
+
+        // Rows start at 0, 10, 30, 60, 100, 150
+        let measured = RowLayout::new(RowHeight::Measured(measure), 100);
+        assert_eq!(measured.visible_rows(0.0..35.0, 0), 0..3);
+        assert_eq!(measured.visible_rows(60.0..100.0, 1), 2..5);
+        assert_eq!(
+            RowLayout::new(RowHeight::Measured(measure), 5).visible_rows(120.0..200.0, 1),
+            3..5
+        );
+        assert!((measured.top(4) - 100.0).abs() < f32::EPSILON);
+        assert!((measured.height(4) - 50.0).abs() < f32::EPSILON);
+        assert!((RowHeight::Measured(measure).top(4) - 100.0).abs() < f32::EPSILON);
+    }
+
+    #[derive(Debug, Default, Resource)]
+    struct Inventory;
+
+    #[derive(Debug)]
+    struct Root;
+
+    impl MavericRoot for Root {
+        type Context<'w, 's> = Res<'w, Inventory>;
+
+        fn set_children(_context: &Self::Context<'_, '_>, commands: &mut impl ChildCommands) {
+            commands.add_child(0, list(), &());
+        }
+    }
+
+    type List = VirtualListNode<Style, u32, Row>;
+
+    fn list() -> List {
+        VirtualListNode {
+            style: Style::default(),
+            data: 7,
+            count: 10_000,
+            row_height: RowHeight::Fixed(20.0),
+            overscan: 3,
+            physics: ScrollPhysics::default(),
+            snap: ScrollSnap::None,
+            scroll_to: None,
+            get_child: |data, index| Some(Row(data * index)),
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row(u32);
+
+    impl MavericNode for Row {
+        type Context<'w, 's> = ();
+
+        fn set_components(_commands: SetComponentCommands<Self, Self::Context<'_, '_>>) {}
+
+        fn set_children<R: MavericRoot>(
+            _commands: SetChildrenCommands<Self, Self::Context<'_, '_>, R>,
+        ) {
+        }
+    }
+
+    fn rows(app: &mut App) -> Vec<u32> {
+        let world = app.world_mut();
+        let mut rows: Vec<u32> = world
+            .query::<&MavericNodeComponent<Row>>()
+            .iter(world)
+            .map(|x| x.node.0)
+            .collect();
+        rows.sort_unstable();
+        rows
+    }
+
+    fn row_entities(app: &mut App) -> Vec<Entity> {
+        let world = app.world_mut();
+        let mut entities: Vec<Entity> = world
+            .query_filtered::<Entity, With<MavericNodeComponent<Row>>>()
+            .iter(world)
+            .collect();
+        entities.sort_unstable();
+        entities
+    }
+
+    #[test]
+    pub fn test_virtual_list() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, MavericWidgetsPlugin));
+        app.init_resource::<Inventory>();
+        app.register_maveric::<Root>();
+        app.register_local_state::<Root, List>();
+        app.update();
+
+        // Nothing has been laid out so only the overscan is visible
+        assert_eq!(rows(&mut app), vec![0, 7, 14]);
+
+        let world = app.world_mut();
+        world.query::<&mut ScrollState>().single_mut(world).offset.y = 200.0;
+        app.update();
+        assert_eq!(rows(&mut app), vec![49, 56, 63, 70, 77, 84]);
+        let entities = row_entities(&mut app);
+
+        // Scrolling by a row reuses the entity of the row which went out of view
+        let world = app.world_mut();
+        world.query::<&mut ScrollState>().single_mut(world).offset.y = 220.0;
+        app.update();
+        assert_eq!(rows(&mut app), vec![56, 63, 70, 77, 84, 91]);
+        assert_eq!(row_entities(&mut app), entities);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+
+/// Adds the plugins which drive the ui widgets: button handlers and styles, text inputs, scroll views,
+/// virtual lists, focus, modals and tooltips.
+///
+/// Add this once, alongside `register_maveric`, if you use any of those widgets.
+/// Plugins which have already been added are skipped, so you can also add only the ones you need.
+#[derive(Debug, Default)]
+pub struct MavericWidgetsPlugin;
+
+impl Plugin for MavericWidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ButtonHandlersPlugin>() {
+            app.add_plugins(ButtonHandlersPlugin);
+        }
+        if !app.is_plugin_added::<ButtonStylesPlugin>() {
+            app.add_plugins(ButtonStylesPlugin);
+        }
+        if !app.is_plugin_added::<TextInputPlugin>() {
+            app.add_plugins(TextInputPlugin);
+        }
+        if !app.is_plugin_added::<ScrollViewPlugin>() {
+            app.add_plugins(ScrollViewPlugin);
+        }
+        if !app.is_plugin_added::<VirtualListPlugin>() {
+            app.add_plugins(VirtualListPlugin);
+        }
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        if !app.is_plugin_added::<ModalPlugin>() {
+            app.add_plugins(ModalPlugin);
+        }
+        if !app.is_plugin_added::<TooltipPlugin>() {
+            app.add_plugins(TooltipPlugin);
+        }
+    }
+}
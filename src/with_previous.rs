@@ -1,6 +1,11 @@
+use std::collections::VecDeque;
+
 use bevy::{
     ecs::{
+        archetype::Archetype,
         component::ComponentId,
+        entity::EntityHashMap,
+        query::QueryFilter,
         system::{ReadOnlySystemParam, SystemParam},
     },
     prelude::*,
@@ -110,9 +115,354 @@ unsafe impl<'w, 's, T: Resource + Clone> SystemParam for WithPrevious<'w, 's, T>
     }
 }
 
+/// A query of a component which also remembers the previous value of each entity's component
+pub struct QueryWithPrevious<'w, 's, T: Component + Clone, F: QueryFilter + 'static = ()> {
+    query: Query<'w, 's, &'static T, F>,
+    previous: &'s EntityHashMap<T>,
+    changed: &'s [Entity],
+    has_changed: bool,
+}
+
+impl<'s, T: Component + Clone, F: QueryFilter + 'static> QueryWithPrevious<'_, 's, T, F> {
+    /// The previous value of this entity's component, if it has changed since the system last ran.
+    /// This is `None` if the component was added since the system last ran
+    #[must_use]
+    pub fn previous_if_changed(&self, entity: Entity) -> Option<&'s T> {
+        if self.changed.contains(&entity) {
+            self.previous.get(&entity)
+        } else {
+            None
+        }
+    }
+
+    /// The entities whose components have changed or been added since the system last ran
+    #[must_use]
+    pub const fn changed_entities(&self) -> &'s [Entity] {
+        self.changed
+    }
+}
+
+impl<T: Component + Clone, F: QueryFilter + 'static> HasChanged
+    for QueryWithPrevious<'_, '_, T, F>
+{
+    fn has_changed(&self) -> bool {
+        self.has_changed
+    }
+}
+
+impl<'w, 's, T: Component + Clone, F: QueryFilter + 'static> std::ops::Deref
+    for QueryWithPrevious<'w, 's, T, F>
+{
+    type Target = Query<'w, 's, &'static T, F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.query
+    }
+}
+
+impl<T: Component + Clone + std::fmt::Debug, F: QueryFilter + 'static> std::fmt::Debug
+    for QueryWithPrevious<'_, '_, T, F>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.query.iter()).finish()
+    }
+}
+
+unsafe impl<T: Component + Clone, F: QueryFilter + 'static> ReadOnlySystemParam
+    for QueryWithPrevious<'_, '_, T, F>
+{
+}
+
+type ChangedQuery<T, F> = Query<'static, 'static, (Entity, &'static T), (F, Changed<T>)>;
+
+pub struct QueryWithPreviousState<T: Component + Clone, F: QueryFilter + 'static> {
+    query_state: <Query<'static, 'static, &'static T, F> as SystemParam>::State,
+    changed_state: <ChangedQuery<T, F> as SystemParam>::State,
+    /// The value of each component before it last changed
+    previous: EntityHashMap<T>,
+    /// The value of each component when the system last ran
+    latest: EntityHashMap<T>,
+    changed: Vec<Entity>,
+}
+
+unsafe impl<T: Component + Clone, F: QueryFilter + 'static> SystemParam
+    for QueryWithPrevious<'_, '_, T, F>
+{
+    type State = QueryWithPreviousState<T, F>;
+    type Item<'world, 'state> = QueryWithPrevious<'world, 'state, T, F>;
+
+    fn init_state(
+        world: &mut World,
+        system_meta: &mut bevy::ecs::system::SystemMeta,
+    ) -> Self::State {
+        QueryWithPreviousState {
+            query_state: <Query<'static, 'static, &'static T, F> as SystemParam>::init_state(
+                world,
+                system_meta,
+            ),
+            changed_state: <ChangedQuery<T, F> as SystemParam>::init_state(world, system_meta),
+            previous: EntityHashMap::default(),
+            latest: EntityHashMap::default(),
+            changed: vec![],
+        }
+    }
+
+    unsafe fn new_archetype(
+        state: &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut bevy::ecs::system::SystemMeta,
+    ) {
+        <Query<'static, 'static, &'static T, F> as SystemParam>::new_archetype(
+            &mut state.query_state,
+            archetype,
+            system_meta,
+        );
+        <ChangedQuery<T, F> as SystemParam>::new_archetype(
+            &mut state.changed_state,
+            archetype,
+            system_meta,
+        );
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &bevy::ecs::system::SystemMeta,
+        world: bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell<'world>,
+        change_tick: bevy::ecs::component::Tick,
+    ) -> Self::Item<'world, 'state> {
+        let query = <Query<'static, 'static, &'static T, F> as SystemParam>::get_param(
+            &mut state.query_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+        let changed = <ChangedQuery<T, F> as SystemParam>::get_param(
+            &mut state.changed_state,
+            system_meta,
+            world,
+            change_tick,
+        );
+
+        state.changed.clear();
+        for (entity, value) in changed.iter() {
+            state.changed.push(entity);
+            match state.latest.insert(entity, value.clone()) {
+                Some(latest) => {
+                    state.previous.insert(entity, latest);
+                }
+                None => {
+                    state.previous.remove(&entity);
+                }
+            }
+        }
+
+        // Forget entities which no longer match the query
+        let count = state.latest.len();
+        state.latest.retain(|entity, _| query.contains(*entity));
+        state.previous.retain(|entity, _| query.contains(*entity));
+        let any_removed = state.latest.len() != count;
+
+        QueryWithPrevious {
+            query,
+            previous: &state.previous,
+            changed: &state.changed,
+            has_changed: any_removed || !state.changed.is_empty(),
+        }
+    }
+}
+
+/// A resource which also remembers its last `N` distinct values
+#[derive(Debug)]
+pub struct WithHistory<'w, 's, T: Resource + Clone + PartialEq, const N: usize> {
+    inner: Res<'w, T>,
+    history: &'s VecDeque<T>,
+}
+
+impl<'s, T: Resource + Clone + PartialEq, const N: usize> WithHistory<'_, 's, T, N> {
+    /// The previous distinct values of the resource, most recent first
+    #[must_use]
+    pub fn history(&self) -> impl ExactSizeIterator<Item = &'s T> + DoubleEndedIterator {
+        self.history.iter()
+    }
+
+    /// The most recent value of the resource which differs from the current value
+    #[must_use]
+    pub fn previous(&self) -> Option<&'s T> {
+        self.history.front()
+    }
+}
+
+impl<T: Resource + Clone + PartialEq, const N: usize> HasChanged for WithHistory<'_, '_, T, N> {
+    fn has_changed(&self) -> bool {
+        self.inner.is_changed()
+    }
+}
+
+impl<T: Resource + Clone + PartialEq, const N: usize> AsRef<T> for WithHistory<'_, '_, T, N> {
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: Resource + Clone + PartialEq, const N: usize> std::ops::Deref
+    for WithHistory<'_, '_, T, N>
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+unsafe impl<T: Resource + Clone + PartialEq, const N: usize> ReadOnlySystemParam
+    for WithHistory<'_, '_, T, N>
+{
+}
+
+pub struct WithHistoryState<T> {
+    component_id: ComponentId,
+    latest: T,
+    history: VecDeque<T>,
+}
+
+unsafe impl<T: Resource + Clone + PartialEq, const N: usize> SystemParam
+    for WithHistory<'_, '_, T, N>
+{
+    type State = WithHistoryState<T>;
+
+    type Item<'world, 'state> = WithHistory<'world, 'state, T, N>;
+
+    fn init_state(
+        world: &mut World,
+        system_meta: &mut bevy::ecs::system::SystemMeta,
+    ) -> Self::State {
+        let component_id = Res::<T>::init_state(world, system_meta);
+        let latest = world.resource::<T>().clone();
+
+        WithHistoryState {
+            component_id,
+            latest,
+            history: VecDeque::with_capacity(N),
+        }
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &bevy::ecs::system::SystemMeta,
+        world: bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell<'world>,
+        change_tick: bevy::ecs::component::Tick,
+    ) -> Self::Item<'world, 'state> {
+        let res = Res::<T>::get_param(&mut state.component_id, system_meta, world, change_tick);
+
+        if res.is_changed() && res.as_ref() != &state.latest {
+            let previous = std::mem::replace(&mut state.latest, res.as_ref().clone());
+            if N > 0 {
+                if state.history.len() >= N {
+                    state.history.pop_back();
+                }
+                state.history.push_front(previous);
+            }
+        }
+
+        WithHistory {
+            inner: res,
+            history: &state.history,
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
-    use crate::{prelude::*, with_previous::WithPrevious};
+    use crate::{
+        has_changed::HasChanged,
+        prelude::*,
+        with_previous::{QueryWithPrevious, WithHistory, WithPrevious},
+    };
+
+    #[test]
+    pub fn test_query_with_previous() {
+        #[derive(Debug, Clone, Copy, PartialEq, Component)]
+        struct Score(u32);
+
+        #[derive(Debug, Default, Resource)]
+        struct Deltas(Vec<Vec<(u32, Option<u32>)>>);
+
+        fn record_deltas(scores: QueryWithPrevious<Score>, mut deltas: ResMut<Deltas>) {
+            if !scores.has_changed() {
+                return;
+            }
+            let mut frame: Vec<_> = scores
+                .changed_entities()
+                .iter()
+                .map(|entity| {
+                    (
+                        scores.get(*entity).unwrap().0,
+                        scores.previous_if_changed(*entity).map(|x| x.0),
+                    )
+                })
+                .collect();
+            frame.sort_unstable();
+            deltas.0.push(frame);
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Deltas>();
+        app.add_systems(Update, record_deltas);
+
+        let first = app.world_mut().spawn(Score(1)).id();
+        let second = app.world_mut().spawn(Score(2)).id();
+        app.update();
+        app.update();
+
+        app.world_mut().get_mut::<Score>(first).unwrap().0 = 5;
+        app.update();
+
+        app.world_mut().get_mut::<Score>(first).unwrap().0 = 6;
+        app.world_mut().get_mut::<Score>(second).unwrap().0 = 3;
+        app.update();
+
+        app.world_mut().despawn(second);
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Deltas>().0,
+            vec![
+                vec![(1, None), (2, None)],
+                vec![(5, Some(1))],
+                vec![(3, Some(2)), (6, Some(5))],
+                vec![]
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_with_history() {
+        #[derive(Debug, Default, Resource, Clone, PartialEq)]
+        struct Score(u32);
+
+        #[derive(Debug, Default, Resource)]
+        struct Histories(Vec<Vec<u32>>);
+
+        fn record_history(score: WithHistory<Score, 2>, mut histories: ResMut<Histories>) {
+            histories.0.push(score.history().map(|x| x.0).collect());
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Score>();
+        app.init_resource::<Histories>();
+        app.add_systems(Update, record_history);
+
+        app.update();
+        for score in [1, 1, 2, 3] {
+            app.world_mut().resource_mut::<Score>().0 = score;
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().resource::<Histories>().0,
+            vec![vec![], vec![0], vec![0], vec![1, 0], vec![2, 1]]
+        );
+    }
 
     #[test]
     pub fn test_with_previous() {